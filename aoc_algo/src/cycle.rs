@@ -0,0 +1,194 @@
+use std::{collections::HashMap, hash::Hash};
+
+// Where a sequence of states starts repeating. State `start + length` is the
+// same as state `start`, counting the initial state as 0.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct CycleInfo {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl CycleInfo {
+    // The earliest step that reaches the same state as step `n`
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+// Remembers every state, so the cycle is found as soon as it first repeats.
+// Loops forever if the states never repeat.
+pub fn detect_cycle<T, F>(initial: T, step: F) -> CycleInfo
+where
+    T: Hash + Eq + Clone,
+    F: FnMut(&T) -> T,
+{
+    detect_cycle_within(initial, usize::MAX, step).expect("The states should eventually repeat")
+}
+
+// None if no state repeats within `max_steps` steps
+pub fn detect_cycle_within<T, F>(initial: T, max_steps: usize, step: F) -> Option<CycleInfo>
+where
+    T: Hash + Eq + Clone,
+    F: FnMut(&T) -> T,
+{
+    walk(initial, max_steps, step).1
+}
+
+// The state after `n` steps, skipping ahead once the states start repeating
+pub fn nth_state<T, F>(initial: T, n: usize, step: F) -> T
+where
+    T: Hash + Eq + Clone,
+    F: FnMut(&T) -> T,
+{
+    let (mut history, cycle) = walk(initial, n, step);
+    match cycle {
+        Some(cycle) => history.swap_remove(cycle.equivalent_step(n)),
+        None => history.pop().expect("The initial state is always kept"),
+    }
+}
+
+// Every state up to the first repeat, or up to `max_steps` if there isn't one
+fn walk<T, F>(initial: T, max_steps: usize, mut step: F) -> (Vec<T>, Option<CycleInfo>)
+where
+    T: Hash + Eq + Clone,
+    F: FnMut(&T) -> T,
+{
+    let mut seen = HashMap::new();
+    let mut history = vec![initial];
+
+    for i in 0..=max_steps {
+        let state = &history[i];
+        if let Some(&start) = seen.get(state) {
+            history.pop();
+            let cycle = CycleInfo {
+                start,
+                length: i - start,
+            };
+            return (history, Some(cycle));
+        }
+        seen.insert(state.clone(), i);
+        if i < max_steps {
+            let next = step(state);
+            history.push(next);
+        }
+    }
+    (history, None)
+}
+
+// Brent's algorithm, which only ever holds two states, for when they are too
+// big to remember them all. Takes a few times as many steps as detect_cycle.
+pub fn detect_cycle_brent<T, F>(initial: T, mut step: F) -> CycleInfo
+where
+    T: PartialEq + Clone,
+    F: FnMut(&T) -> T,
+{
+    // Find the length by moving the tortoise up to the hare at each power of
+    // two until the hare laps it
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // With the hare a whole cycle ahead, they first meet where the cycle starts
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    CycleInfo { start, length }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0, 1, 2, 3, 4, 5, 3, 4, 5, ...
+    fn tail_then_loop(n: &u32) -> u32 {
+        if *n == 5 {
+            3
+        } else {
+            n + 1
+        }
+    }
+
+    #[test]
+    fn test_detect_cycle() {
+        let expected = CycleInfo {
+            start: 3,
+            length: 3,
+        };
+        assert_eq!(detect_cycle(0, tail_then_loop), expected);
+        assert_eq!(detect_cycle_brent(0, tail_then_loop), expected);
+    }
+
+    #[test]
+    fn test_cycle_from_the_start() {
+        let expected = CycleInfo {
+            start: 0,
+            length: 7,
+        };
+        assert_eq!(detect_cycle(0, |n| (n + 1) % 7), expected);
+        assert_eq!(detect_cycle_brent(0, |n| (n + 1) % 7), expected);
+
+        let fixed_point = CycleInfo {
+            start: 0,
+            length: 1,
+        };
+        assert_eq!(detect_cycle('a', |&c| c), fixed_point);
+        assert_eq!(detect_cycle_brent('a', |&c| c), fixed_point);
+    }
+
+    #[test]
+    fn test_detect_cycle_within() {
+        assert_eq!(detect_cycle_within(0, 5, tail_then_loop), None);
+        assert_eq!(
+            detect_cycle_within(0, 6, tail_then_loop),
+            Some(CycleInfo {
+                start: 3,
+                length: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_nth_state() {
+        let mut state = 0;
+        for n in 0..20 {
+            assert_eq!(nth_state(0, n, tail_then_loop), state);
+            state = tail_then_loop(&state);
+        }
+        assert_eq!(nth_state(0, 1_000_000_000, tail_then_loop), 4);
+    }
+
+    #[test]
+    fn test_equivalent_step() {
+        let cycle = CycleInfo {
+            start: 3,
+            length: 3,
+        };
+        assert_eq!(cycle.equivalent_step(2), 2);
+        assert_eq!(cycle.equivalent_step(3), 3);
+        assert_eq!(cycle.equivalent_step(7), 4);
+        assert_eq!(cycle.equivalent_step(9), 3);
+    }
+}
@@ -0,0 +1,9 @@
+mod cycle;
+mod memo;
+mod ranges;
+mod union_find;
+
+pub use cycle::*;
+pub use memo::*;
+pub use ranges::*;
+pub use union_find::*;
@@ -0,0 +1,90 @@
+use std::{collections::HashMap, hash::Hash};
+
+// A cache for recursive counting. The closure given to `get_or_compute` gets
+// the memo back so it can recurse through it, e.g.
+// `memo.get_or_compute(key, |memo| count(rest, memo) + ...)`.
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+}
+
+impl<K: Hash + Eq, V: Clone> Memo<K, V> {
+    pub fn new() -> Memo<K, V> {
+        Memo {
+            cache: HashMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    // The cached value for `key`, or else whatever `compute` gives, which is
+    // then cached. `compute` must not depend on anything outside the key.
+    pub fn get_or_compute<F>(&mut self, key: K, compute: F) -> V
+    where
+        F: FnOnce(&mut Self) -> V,
+    {
+        if let Some(value) = self.cache.get(&key) {
+            return value.clone();
+        }
+        let value = compute(self);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Default for Memo<K, V> {
+    fn default() -> Memo<K, V> {
+        Memo::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fibonacci(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+        if n < 2 {
+            return n;
+        }
+        memo.get_or_compute(n, |memo| fibonacci(n - 1, memo) + fibonacci(n - 2, memo))
+    }
+
+    #[test]
+    fn test_recursion() {
+        let mut memo = Memo::new();
+        assert_eq!(fibonacci(90, &mut memo), 2_880_067_194_370_816_120);
+        assert_eq!(memo.len(), 89);
+        assert_eq!(memo.get(&10), Some(&55));
+    }
+
+    #[test]
+    fn test_computes_each_key_once() {
+        let mut memo = Memo::new();
+        let mut calls = 0;
+        for _ in 0..3 {
+            memo.get_or_compute("key", |_| {
+                calls += 1;
+                7
+            });
+        }
+        assert_eq!(calls, 1);
+
+        memo.clear();
+        assert!(memo.is_empty());
+        assert_eq!(memo.get_or_compute("key", |_| 8), 8);
+    }
+}
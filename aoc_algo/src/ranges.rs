@@ -0,0 +1,249 @@
+use std::ops::Range;
+
+// A set of u64s stored as sorted, disjoint ranges. Overlapping and touching
+// ranges are merged as they are added, so each gap between ranges is real.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct RangeSet {
+    ranges: Vec<Range<u64>>,
+}
+
+impl RangeSet {
+    pub fn new() -> RangeSet {
+        RangeSet::default()
+    }
+
+    pub fn ranges(&self) -> &[Range<u64>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    // How many values are in the set, not how many ranges
+    pub fn len(&self) -> u64 {
+        self.ranges.iter().map(|r| r.end - r.start).sum()
+    }
+
+    pub fn min(&self) -> Option<u64> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    pub fn max(&self) -> Option<u64> {
+        self.ranges.last().map(|r| r.end - 1)
+    }
+
+    pub fn contains(&self, value: u64) -> bool {
+        let i = self.ranges.partition_point(|r| r.end <= value);
+        self.ranges.get(i).is_some_and(|r| r.start <= value)
+    }
+
+    pub fn insert(&mut self, range: Range<u64>) {
+        if range.is_empty() {
+            return;
+        }
+        // Everything from `first` up to `last` touches the new range
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        let last = self.ranges.partition_point(|r| r.start <= range.end);
+        let merged = match self.ranges.get(first..last) {
+            Some([head, .., tail]) => head.start.min(range.start)..tail.end.max(range.end),
+            Some([only]) => only.start.min(range.start)..only.end.max(range.end),
+            _ => range,
+        };
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    pub fn union(&self, other: &RangeSet) -> RangeSet {
+        let mut union = self.clone();
+        for range in &other.ranges {
+            union.insert(range.clone());
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &RangeSet) -> RangeSet {
+        let mut ranges = Vec::new();
+        let (mut a, mut b) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            let overlap = x.start.max(y.start)..x.end.min(y.end);
+            if !overlap.is_empty() {
+                ranges.push(overlap);
+            }
+            // Whichever ends first can't overlap anything else
+            if x.end < y.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        RangeSet { ranges }
+    }
+
+    // The values in this set but not in `other`
+    pub fn difference(&self, other: &RangeSet) -> RangeSet {
+        let mut ranges = Vec::new();
+        for range in &self.ranges {
+            let mut start = range.start;
+            let first = other.ranges.partition_point(|r| r.end <= range.start);
+            for cut in other.ranges[first..]
+                .iter()
+                .take_while(|r| r.start < range.end)
+            {
+                if cut.start > start {
+                    ranges.push(start..cut.start);
+                }
+                start = start.max(cut.end);
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        RangeSet { ranges }
+    }
+
+    // Moves every value by `offset`. Panics if any would leave the u64 range.
+    pub fn shift(&self, offset: i64) -> RangeSet {
+        let shift = |value: u64| {
+            value
+                .checked_add_signed(offset)
+                .expect("Shifted range should stay within u64")
+        };
+        let ranges = self
+            .ranges
+            .iter()
+            .map(|r| shift(r.start)..shift(r.end))
+            .collect();
+        RangeSet { ranges }
+    }
+}
+
+impl From<Range<u64>> for RangeSet {
+    fn from(range: Range<u64>) -> RangeSet {
+        let mut set = RangeSet::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl FromIterator<Range<u64>> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = Range<u64>>>(iter: I) -> RangeSet {
+        let mut set = RangeSet::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+// Moves values that fall in a source range by that range's offset and leaves
+// every other value alone. The first matching range wins, as in day 5's maps.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RangeMap {
+    entries: Vec<(Range<u64>, i64)>,
+}
+
+impl RangeMap {
+    pub fn new() -> RangeMap {
+        RangeMap::default()
+    }
+
+    // Sends `source.start` to `destination` and the rest of `source` after it
+    pub fn insert(&mut self, source: Range<u64>, destination: u64) {
+        let offset = destination as i64 - source.start as i64;
+        self.entries.push((source, offset));
+    }
+
+    pub fn map(&self, value: u64) -> u64 {
+        self.entries
+            .iter()
+            .find(|(source, _)| source.contains(&value))
+            .map_or(value, |(_, offset)| {
+                value
+                    .checked_add_signed(*offset)
+                    .expect("Mapped value should stay within u64")
+            })
+    }
+
+    // Every value of `values` mapped at once, without visiting them one by one
+    pub fn map_set(&self, values: &RangeSet) -> RangeSet {
+        let mut mapped = RangeSet::new();
+        let mut unmatched = values.clone();
+        for (source, offset) in &self.entries {
+            let source = RangeSet::from(source.clone());
+            mapped = mapped.union(&unmatched.intersection(&source).shift(*offset));
+            unmatched = unmatched.difference(&source);
+        }
+        mapped.union(&unmatched)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_merges() {
+        let mut set: RangeSet = [10..20, 30..40].into_iter().collect();
+        assert_eq!(set.ranges(), &[10..20, 30..40]);
+
+        set.insert(20..25);
+        assert_eq!(set.ranges(), &[10..25, 30..40]);
+        set.insert(0..5);
+        set.insert(5..5);
+        assert_eq!(set.ranges(), &[0..5, 10..25, 30..40]);
+        set.insert(3..35);
+        assert_eq!(set, RangeSet::from(0..40));
+        assert_eq!(set.len(), 40);
+    }
+
+    #[test]
+    fn test_contains_and_bounds() {
+        let set: RangeSet = [10..20, 30..40].into_iter().collect();
+        assert!(set.contains(10));
+        assert!(set.contains(39));
+        assert!(!set.contains(20));
+        assert!(!set.contains(5));
+        assert_eq!(set.min(), Some(10));
+        assert_eq!(set.max(), Some(39));
+        assert_eq!(RangeSet::new().min(), None);
+    }
+
+    #[test]
+    fn test_intersection_and_difference() {
+        let a: RangeSet = [0..10, 20..30, 40..50].into_iter().collect();
+        let b: RangeSet = [5..25, 45..60].into_iter().collect();
+
+        assert_eq!(a.intersection(&b).ranges(), &[5..10, 20..25, 45..50]);
+        assert_eq!(a.difference(&b).ranges(), &[0..5, 25..30, 40..45]);
+        assert_eq!(b.difference(&a).ranges(), &[10..20, 50..60]);
+        assert_eq!(a.union(&b).ranges(), &[0..30, 40..60]);
+        assert!(a.intersection(&RangeSet::new()).is_empty());
+    }
+
+    #[test]
+    fn test_shift() {
+        let set: RangeSet = [5..10, 20..30].into_iter().collect();
+        assert_eq!(set.shift(-5).ranges(), &[0..5, 15..25]);
+        assert_eq!(set.shift(100).ranges(), &[105..110, 120..130]);
+    }
+
+    #[test]
+    fn test_range_map() {
+        // Day 5's seed-to-soil map
+        let mut map = RangeMap::new();
+        map.insert(98..100, 50);
+        map.insert(50..98, 52);
+
+        assert_eq!(map.map(79), 81);
+        assert_eq!(map.map(99), 51);
+        assert_eq!(map.map(10), 10);
+
+        let seeds: RangeSet = [79..93, 95..105].into_iter().collect();
+        let soil = map.map_set(&seeds);
+        assert_eq!(soil.ranges(), &[50..52, 81..95, 97..105]);
+        assert_eq!(soil.len(), seeds.len());
+    }
+}
@@ -0,0 +1,140 @@
+// Disjoint sets over the elements 0..len, for connectivity puzzles. Map other
+// keys to indices first, e.g. through an IndexMap.
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parents: Vec<usize>,
+    ranks: Vec<u8>,
+    sizes: Vec<usize>,
+    components: usize,
+}
+
+impl UnionFind {
+    // Every element starts in a set of its own
+    pub fn new(len: usize) -> UnionFind {
+        UnionFind {
+            parents: (0..len).collect(),
+            ranks: vec![0; len],
+            sizes: vec![1; len],
+            components: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    // The representative of the set holding `element`
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+        // Point everything on the way straight at the root
+        let mut current = element;
+        while current != root {
+            current = std::mem::replace(&mut self.parents[current], root);
+        }
+        root
+    }
+
+    // False if the two were already in the same set
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        // Hang the shallower tree under the deeper one
+        let (root, child) = if self.ranks[a] < self.ranks[b] {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        self.parents[child] = root;
+        self.sizes[root] += self.sizes[child];
+        if self.ranks[root] == self.ranks[child] {
+            self.ranks[root] += 1;
+        }
+        self.components -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    // How many elements share a set with `element`, itself included
+    pub fn size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.sizes[root]
+    }
+
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    // The elements in the same set as `element`, in ascending order
+    pub fn members(&mut self, element: usize) -> impl Iterator<Item = usize> + '_ {
+        let root = self.find(element);
+        (0..self.len()).filter(move |&other| self.find(other) == root)
+    }
+
+    // Every set, each in ascending order and ordered by its smallest element
+    pub fn groups(&mut self) -> Vec<Vec<usize>> {
+        let mut by_root = vec![Vec::new(); self.len()];
+        for element in 0..self.len() {
+            let root = self.find(element);
+            by_root[root].push(element);
+        }
+        let mut groups: Vec<Vec<usize>> = by_root.into_iter().filter(|g| !g.is_empty()).collect();
+        groups.sort();
+        groups
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_union_and_find() {
+        let mut sets = UnionFind::new(6);
+        assert_eq!(sets.components(), 6);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+
+        assert!(sets.connected(0, 3));
+        assert!(!sets.connected(0, 4));
+        assert_eq!(sets.components(), 3);
+        assert_eq!(sets.size(2), 4);
+        assert_eq!(sets.size(5), 1);
+    }
+
+    #[test]
+    fn test_members_and_groups() {
+        let mut sets = UnionFind::new(5);
+        sets.union(4, 1);
+        sets.union(3, 0);
+        sets.union(0, 4);
+
+        assert_eq!(sets.members(1).collect::<Vec<_>>(), vec![0, 1, 3, 4]);
+        assert_eq!(sets.members(2).collect::<Vec<_>>(), vec![2]);
+        assert_eq!(sets.groups(), vec![vec![0, 1, 3, 4], vec![2]]);
+    }
+
+    #[test]
+    fn test_long_chain_stays_shallow() {
+        let mut sets = UnionFind::new(10_000);
+        for i in 1..10_000 {
+            sets.union(i - 1, i);
+        }
+        assert_eq!(sets.components(), 1);
+        assert_eq!(sets.size(0), 10_000);
+        assert!(sets.ranks.iter().all(|&rank| rank <= 14));
+    }
+}
@@ -0,0 +1,92 @@
+use std::{
+    ffi::OsString,
+    fmt,
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+
+// Where a solver reads its puzzle input from. A `-` on the command line means
+// stdin, so input can be piped in from other tools.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    // The source given on the command line, or else the puzzle input next to
+    // the crate so the binary no longer depends on the directory it is run from
+    pub fn from_arg_or_default(arg: Option<OsString>, crate_dir: &str) -> InputSource {
+        arg.map(InputSource::from)
+            .unwrap_or_else(|| InputSource::File(Path::new(crate_dir).join("puzzle_input.txt")))
+    }
+
+    pub fn read(&self) -> Result<String> {
+        match self {
+            InputSource::File(path) => std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read {}", path.display())),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .context("Failed to read from stdin")?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+impl From<OsString> for InputSource {
+    fn from(arg: OsString) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(arg))
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults_to_crate_dir() {
+        let source = InputSource::from_arg_or_default(None, "/some/crate");
+        assert_eq!(
+            source,
+            InputSource::File(PathBuf::from("/some/crate/puzzle_input.txt"))
+        );
+    }
+
+    #[test]
+    fn test_prefers_argument() {
+        let source = InputSource::from_arg_or_default(Some("example.txt".into()), "/some/crate");
+        assert_eq!(source, InputSource::File(PathBuf::from("example.txt")));
+    }
+
+    #[test]
+    fn test_dash_is_stdin() {
+        let source = InputSource::from_arg_or_default(Some("-".into()), "/some/crate");
+        assert_eq!(source, InputSource::Stdin);
+    }
+
+    #[test]
+    fn test_read_missing_file() {
+        let error = InputSource::File(PathBuf::from("/does/not/exist.txt"))
+            .read()
+            .unwrap_err();
+        assert_eq!(error.to_string(), "Failed to read /does/not/exist.txt");
+    }
+}
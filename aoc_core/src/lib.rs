@@ -0,0 +1,203 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use anyhow::{bail, Result};
+
+mod input;
+mod progress;
+mod registry;
+
+pub use input::*;
+pub use progress::*;
+pub use registry::*;
+
+// A day's puzzle. The input is parsed once and both parts are solved from it.
+pub trait Solution {
+    type Parsed;
+    type Answer: Display;
+
+    fn parse(input: &str) -> Result<Self::Parsed>;
+    fn part1(parsed: &Self::Parsed) -> Self::Answer;
+    fn part2(parsed: &Self::Parsed) -> Self::Answer;
+
+    // Parses the input and solves a single part, timing each phase
+    fn solve_timed(input: &str, part: u8) -> Result<TimedAnswer> {
+        let solve_part = match part {
+            1 => Self::part1,
+            2 => Self::part2,
+            _ => bail!("There is no part {part}, only parts 1 and 2"),
+        };
+
+        let start = Instant::now();
+        let parsed = tracing::debug_span!("parse").in_scope(|| Self::parse(input))?;
+        let parse = start.elapsed();
+        tracing::debug!(?parse, "parsed input");
+
+        let start = Instant::now();
+        let answer = tracing::debug_span!("part", part).in_scope(|| solve_part(&parsed));
+        let solve = start.elapsed();
+        tracing::debug!(?solve, "solved part");
+
+        Ok(TimedAnswer {
+            answer: answer.to_string(),
+            parse: Some(parse),
+            solve,
+        })
+    }
+
+    // Describes the parsed input, to tell a parsing bug from a solving one
+    fn summarize(_parsed: &Self::Parsed) -> String {
+        "Parsed without errors".to_owned()
+    }
+
+    fn parse_only(input: &str) -> Result<String> {
+        let parsed = tracing::debug_span!("parse").in_scope(|| Self::parse(input))?;
+        Ok(Self::summarize(&parsed))
+    }
+
+    // For callers that only want the answer
+    fn solve(input: &str, part: u8) -> Result<String> {
+        Self::solve_timed(input, part).map(|timed| timed.answer)
+    }
+}
+
+// An answer along with how long it took to get. Parts registered as a single
+// function parse and solve in one go, so they have no separate parse time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimedAnswer {
+    pub answer: String,
+    pub parse: Option<Duration>,
+    pub solve: Duration,
+}
+
+impl TimedAnswer {
+    pub fn total(&self) -> Duration {
+        self.parse.unwrap_or_default() + self.solve
+    }
+}
+
+// Times a solver that does its own parsing
+pub fn time_unparsed(solve: impl FnOnce() -> Result<String>) -> Result<TimedAnswer> {
+    let start = Instant::now();
+    let answer = solve()?;
+    Ok(TimedAnswer {
+        answer,
+        parse: None,
+        solve: start.elapsed(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Parsed = Vec<u32>;
+        type Answer = u32;
+
+        fn parse(input: &str) -> Result<Vec<u32>> {
+            Ok(input
+                .split_whitespace()
+                .map(str::parse)
+                .collect::<Result<_, _>>()?)
+        }
+
+        fn part1(parsed: &Vec<u32>) -> u32 {
+            parsed.iter().sum()
+        }
+
+        fn part2(parsed: &Vec<u32>) -> u32 {
+            parsed.iter().product()
+        }
+
+        fn summarize(parsed: &Vec<u32>) -> String {
+            format!("{} numbers", parsed.len())
+        }
+    }
+
+    #[test]
+    fn test_solve_selects_part() {
+        assert_eq!(Sum::solve("2 3 4", 1).unwrap(), "9");
+        assert_eq!(Sum::solve("2 3 4", 2).unwrap(), "24");
+    }
+
+    #[test]
+    fn test_solve_unknown_part() {
+        assert!(Sum::solve("2 3 4", 3).is_err());
+    }
+
+    #[test]
+    fn test_solve_timed_splits_parse_and_solve() {
+        let timed = Sum::solve_timed("2 3 4", 1).unwrap();
+        assert_eq!(timed.answer, "9");
+        assert!(timed.parse.is_some());
+        assert_eq!(timed.total(), timed.parse.unwrap() + timed.solve);
+
+        let words = find_registration(DEFAULT_YEAR, 25, 1).unwrap();
+        assert_eq!((words.solve)("2 3 4").unwrap().parse, None);
+    }
+
+    #[test]
+    fn test_solve_parse_error() {
+        assert!(Sum::solve("2 three 4", 1).is_err());
+    }
+
+    #[test]
+    fn test_parse_only() {
+        assert_eq!(Sum::parse_only("2 3 4").unwrap(), "3 numbers");
+        assert!(Sum::parse_only("2 three 4").is_err());
+
+        let sum = find_registration(DEFAULT_YEAR, 24, 1).unwrap();
+        assert_eq!((sum.parse.unwrap())("2 3").unwrap(), "2 numbers");
+        assert!(find_registration(DEFAULT_YEAR, 25, 1)
+            .unwrap()
+            .parse
+            .is_none());
+    }
+
+    fn count_words(input: &str) -> usize {
+        input.split_whitespace().count()
+    }
+
+    register_solution!(24, Sum);
+    register_solution!(25, 1, count_words);
+    register_solution!(year = 2024, 1, 1, count_words);
+
+    #[test]
+    fn test_registered_solutions_are_found() {
+        let sum =
+            find_registration(DEFAULT_YEAR, 24, 2).expect("Day 24 part 2 should be registered");
+        assert_eq!((sum.solve)("2 3 4").unwrap().answer, "24");
+
+        let words =
+            find_registration(DEFAULT_YEAR, 25, 1).expect("Day 25 part 1 should be registered");
+        assert_eq!((words.solve)("2 3 4").unwrap().answer, "3");
+
+        assert!(find_registration(DEFAULT_YEAR, 25, 2).is_none());
+    }
+
+    #[test]
+    fn test_registrations_are_ordered() {
+        let parts: Vec<_> = registrations()
+            .iter()
+            .map(|r| (r.year, r.day, r.part))
+            .collect();
+        assert_eq!(
+            parts,
+            vec![(2023, 24, 1), (2023, 24, 2), (2023, 25, 1), (2024, 1, 1)]
+        );
+        assert_eq!(registered_days(DEFAULT_YEAR), vec![24, 25]);
+        assert_eq!(registered_days(2024), vec![1]);
+    }
+
+    #[test]
+    fn test_registrations_are_kept_apart_by_year() {
+        assert_eq!(registrations_for(2024).len(), 1);
+        assert!(find_registration(2024, 1, 1).is_some());
+        assert!(find_registration(DEFAULT_YEAR, 1, 1).is_none());
+    }
+}
@@ -0,0 +1,65 @@
+use std::sync::OnceLock;
+
+// Somewhere to report progress to, installed once by whatever is running the
+// solvers. Solvers never see it directly, they ask for a `Progress` with
+// `progress` and it does nothing when no sink is installed, as in tests and
+// benchmarks.
+pub trait ProgressSink: Send + Sync {
+    // `None` for loops that cannot know how long they will run
+    fn start(&self, label: &str, total: Option<u64>) -> Box<dyn ProgressBar>;
+}
+
+pub trait ProgressBar: Send + Sync {
+    fn inc(&self, delta: u64);
+    fn finish(&self);
+}
+
+static SINK: OnceLock<Box<dyn ProgressSink>> = OnceLock::new();
+
+// Only the first sink installed is used
+pub fn set_progress_sink(sink: Box<dyn ProgressSink>) {
+    let _ = SINK.set(sink);
+}
+
+pub fn progress(label: &str, total: u64) -> Progress {
+    Progress(SINK.get().map(|sink| sink.start(label, Some(total))))
+}
+
+// Counts steps without a total, for loops that stop once they find something
+// rather than after a known number of steps
+pub fn spinner(label: &str) -> Progress {
+    Progress(SINK.get().map(|sink| sink.start(label, None)))
+}
+
+// A handle to one long running loop. It is finished when dropped, so a loop
+// that exits early, like day 14 finding its cycle, still clears its bar.
+pub struct Progress(Option<Box<dyn ProgressBar>>);
+
+impl Progress {
+    pub fn inc(&self, delta: u64) {
+        if let Some(bar) = &self.0 {
+            bar.inc(delta);
+        }
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        if let Some(bar) = &self.0 {
+            bar.finish();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_without_sink_does_nothing() {
+        let progress = progress("counting", 10);
+        assert!(progress.0.is_none());
+        progress.inc(5);
+        assert!(spinner("searching").0.is_none());
+    }
+}
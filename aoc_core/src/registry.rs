@@ -0,0 +1,128 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+
+use crate::TimedAnswer;
+
+// Re-exported so `register_solution!` works without every day depending on
+// inventory directly
+#[doc(hidden)]
+pub use inventory;
+
+// The year this workspace started with. Its days sit at the top of the
+// workspace and register without naming a year.
+pub const DEFAULT_YEAR: u16 = 2023;
+
+// A single solved part, collected at startup from every linked day crate
+pub struct Registration {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub solve: fn(&str) -> Result<TimedAnswer>,
+    // Parses without solving and describes the result. Only a `Solution` has a
+    // parser that can be run on its own.
+    pub parse: Option<fn(&str) -> Result<String>>,
+}
+
+inventory::collect!(Registration);
+
+// Every registered part, ordered by year, day and then part
+pub fn registrations() -> Vec<&'static Registration> {
+    let mut registrations: Vec<_> = inventory::iter::<Registration>.into_iter().collect();
+    registrations.sort_by_key(|r| (r.year, r.day, r.part));
+    registrations
+}
+
+pub fn registrations_for(year: u16) -> Vec<&'static Registration> {
+    let mut registrations = registrations();
+    registrations.retain(|r| r.year == year);
+    registrations
+}
+
+// Every day of the year with at least one registered part, in order
+pub fn registered_days(year: u16) -> Vec<u8> {
+    let mut days: Vec<_> = registrations_for(year).iter().map(|r| r.day).collect();
+    days.dedup();
+    days
+}
+
+pub fn find_registration(year: u16, day: u8, part: u8) -> Option<&'static Registration> {
+    inventory::iter::<Registration>
+        .into_iter()
+        .find(|r| r.year == year && r.day == day && r.part == part)
+}
+
+// Lets a registered function return either a bare answer or a Result of one
+pub trait IntoAnswer {
+    fn into_answer(self) -> Result<String>;
+}
+
+impl<T: Display> IntoAnswer for Result<T> {
+    fn into_answer(self) -> Result<String> {
+        self.map(|answer| answer.to_string())
+    }
+}
+
+macro_rules! impl_into_answer {
+    ($($t:ty),*) => {
+        $(
+            impl IntoAnswer for $t {
+                fn into_answer(self) -> Result<String> {
+                    Ok(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_into_answer!(i32, i64, u32, u64, usize, String);
+
+// Registers a day with the runner, either as a `Solution` covering both parts
+// or as a single part backed by a plain function. Days from a year other than
+// `DEFAULT_YEAR` name it first:
+//
+//     register_solution!(16, Day16);
+//     register_solution!(6, 2, solve_part_kerned);
+//     register_solution!(year = 2024, 1, Day1);
+#[macro_export]
+macro_rules! register_solution {
+    (year = $year:expr, $day:literal, $solution:ty) => {
+        $crate::inventory::submit! {
+            $crate::Registration {
+                year: $year,
+                day: $day,
+                part: 1,
+                solve: |input| <$solution as $crate::Solution>::solve_timed(input, 1),
+                parse: Some(|input| <$solution as $crate::Solution>::parse_only(input)),
+            }
+        }
+        $crate::inventory::submit! {
+            $crate::Registration {
+                year: $year,
+                day: $day,
+                part: 2,
+                solve: |input| <$solution as $crate::Solution>::solve_timed(input, 2),
+                parse: Some(|input| <$solution as $crate::Solution>::parse_only(input)),
+            }
+        }
+    };
+    (year = $year:expr, $day:literal, $part:literal, $solve:expr) => {
+        $crate::inventory::submit! {
+            $crate::Registration {
+                year: $year,
+                day: $day,
+                part: $part,
+                solve: |input| {
+                    $crate::time_unparsed(|| $crate::IntoAnswer::into_answer($solve(input)))
+                },
+                parse: None,
+            }
+        }
+    };
+    ($day:literal, $solution:ty) => {
+        $crate::register_solution!(year = $crate::DEFAULT_YEAR, $day, $solution);
+    };
+    ($day:literal, $part:literal, $solve:expr) => {
+        $crate::register_solution!(year = $crate::DEFAULT_YEAR, $day, $part, $solve);
+    };
+}
@@ -0,0 +1,131 @@
+use std::{
+    fmt::Debug,
+    hash::Hash,
+    ops::{Add, Sub},
+};
+
+use crate::{search::Search, PriorityQueue};
+
+// The cheapest path from `start` to the first node that `is_goal` accepts, and
+// its cost. `heuristic` must never overestimate the cost left to reach a goal,
+// and debug builds panic if it does anywhere along the path that was found.
+pub fn astar<N, C, F, I, H, G>(
+    start: N,
+    mut neighbors: F,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Option<(Vec<N>, C)>
+where
+    N: Hash + Eq + Clone + Debug,
+    C: Ord + Copy + Default + Add<Output = C> + Sub<Output = C> + Debug,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    let mut search = Search::new(start.clone(), C::default());
+    let mut frontier = PriorityQueue::new();
+    frontier.push(start.clone(), heuristic(&start));
+
+    while let Some((node, _)) = frontier.pop() {
+        let cost = search.distances[&node];
+        if is_goal(&node) {
+            let path = search.path_to(&node)?;
+            if cfg!(debug_assertions) {
+                check_admissible(&path, &search, cost, &mut heuristic);
+            }
+            return Some((path, cost));
+        }
+        for (next, edge) in neighbors(&node) {
+            let next_cost = cost + edge;
+            if search
+                .distances
+                .get(&next)
+                .is_some_and(|&known| known <= next_cost)
+            {
+                continue;
+            }
+            search.distances.insert(next.clone(), next_cost);
+            search.predecessors.insert(next.clone(), node.clone());
+            let estimate = next_cost + heuristic(&next);
+            frontier.push(next, estimate);
+        }
+    }
+    None
+}
+
+fn check_admissible<N, C, H>(path: &[N], search: &Search<N, C>, total: C, heuristic: &mut H)
+where
+    N: Hash + Eq + Clone + Debug,
+    C: Ord + Copy + Sub<Output = C> + Debug,
+    H: FnMut(&N) -> C,
+{
+    for node in path {
+        let remaining = total - search.distances[node];
+        let estimate = heuristic(node);
+        assert!(
+            estimate <= remaining,
+            "The heuristic overestimates at {node:?}: {estimate:?} but only {remaining:?} remains"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Moves one step at a time on a 5x5 grid, where entering a cell in column
+    // 2 costs 5 unless it is in the bottom row
+    fn neighbors(&(x, y): &(u32, u32)) -> Vec<((u32, u32), u32)> {
+        let cost = |(x, y)| if x == 2 && y != 4 { 5 } else { 1 };
+        let mut next = Vec::new();
+        if x < 4 {
+            next.push((x + 1, y));
+        }
+        if y < 4 {
+            next.push((x, y + 1));
+        }
+        if x > 0 {
+            next.push((x - 1, y));
+        }
+        if y > 0 {
+            next.push((x, y - 1));
+        }
+        next.into_iter().map(|p| (p, cost(p))).collect()
+    }
+
+    fn manhattan(&(x, y): &(u32, u32)) -> u32 {
+        x.abs_diff(4) + y
+    }
+
+    #[test]
+    fn test_astar() {
+        let (path, cost) = astar((0, 0), neighbors, manhattan, |&p| p == (4, 0))
+            .expect("The goal should be reachable");
+        // Straight through costs 8, around the bottom costs 12
+        assert_eq!(cost, 8);
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(4, 0)));
+        assert_eq!(path.len(), 5);
+    }
+
+    #[test]
+    fn test_astar_matches_dijkstra() {
+        let goal = (4, 0);
+        let (_, cost) = astar((0, 0), neighbors, |_| 0, |&p| p == goal).unwrap();
+        let search = crate::dijkstra((0, 0), neighbors);
+        assert_eq!(Some(&cost), search.distance(&goal));
+    }
+
+    #[test]
+    fn test_astar_unreachable() {
+        assert_eq!(astar((0, 0), neighbors, |_| 0, |&p| p == (5, 5)), None);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "The heuristic overestimates")]
+    fn test_astar_checks_admissibility() {
+        astar((0, 0), neighbors, |&p| manhattan(&p) * 10, |&p| p == (4, 0));
+    }
+}
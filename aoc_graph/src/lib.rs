@@ -0,0 +1,9 @@
+mod astar;
+mod min_cut;
+mod queue;
+mod search;
+
+pub use astar::*;
+pub use min_cut::*;
+pub use queue::*;
+pub use search::*;
@@ -0,0 +1,179 @@
+use std::{
+    collections::{BinaryHeap, HashMap},
+    hash::Hash,
+};
+
+use crate::bfs;
+
+// The fewest edges whose removal splits a graph in two
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinCut<N> {
+    pub edges: Vec<(N, N)>,
+    // How many nodes end up on each side
+    pub sizes: (usize, usize),
+}
+
+// A minimum cut of the undirected graph made of `edges`, by Stoer-Wagner.
+// Repeated edges count once each and self loops are ignored. None if the
+// graph has fewer than two nodes.
+pub fn min_cut<N, I>(edges: I) -> Option<MinCut<N>>
+where
+    N: Hash + Eq + Clone,
+    I: IntoIterator<Item = (N, N)>,
+{
+    let edges: Vec<(N, N)> = edges.into_iter().collect();
+    let mut indices: HashMap<N, usize> = HashMap::new();
+    for node in edges.iter().flat_map(|(a, b)| [a, b]) {
+        let next = indices.len();
+        indices.entry(node.clone()).or_insert(next);
+    }
+    let len = indices.len();
+    if len < 2 {
+        return None;
+    }
+
+    let mut weights: Vec<HashMap<usize, usize>> = vec![HashMap::new(); len];
+    for (a, b) in &edges {
+        let (a, b) = (indices[a], indices[b]);
+        if a != b {
+            *weights[a].entry(b).or_default() += 1;
+            *weights[b].entry(a).or_default() += 1;
+        }
+    }
+    // A graph in pieces splits for free. Otherwise every phase below reaches
+    // all the remaining nodes.
+    let reached = bfs(0, |&node| weights[node].keys().copied().collect::<Vec<_>>());
+    if reached.distances.len() < len {
+        return Some(MinCut {
+            edges: Vec::new(),
+            sizes: (reached.distances.len(), len - reached.distances.len()),
+        });
+    }
+
+    // The original nodes merged into each remaining node
+    let mut merged: Vec<Vec<usize>> = (0..len).map(|i| vec![i]).collect();
+    let mut active: Vec<usize> = (0..len).collect();
+    let mut best: Option<(usize, Vec<usize>)> = None;
+
+    while active.len() > 1 {
+        let (s, t, cut) = minimum_cut_phase(&weights, &active, len);
+        if best.as_ref().is_none_or(|(weight, _)| cut < *weight) {
+            best = Some((cut, merged[t].clone()));
+        }
+        // Fold t into s
+        let t_weights = std::mem::take(&mut weights[t]);
+        for (v, w) in t_weights {
+            weights[v].remove(&t);
+            if v != s {
+                *weights[s].entry(v).or_default() += w;
+                *weights[v].entry(s).or_default() += w;
+            }
+        }
+        let t_nodes = std::mem::take(&mut merged[t]);
+        merged[s].extend(t_nodes);
+        active.retain(|&node| node != t);
+    }
+
+    let (_, side) = best?;
+    let mut in_side = vec![false; len];
+    for node in &side {
+        in_side[*node] = true;
+    }
+    let edges = edges
+        .into_iter()
+        .filter(|(a, b)| in_side[indices[a]] != in_side[indices[b]])
+        .collect();
+    Some(MinCut {
+        edges,
+        sizes: (side.len(), len - side.len()),
+    })
+}
+
+// Adds nodes one at a time, always the one most tightly connected to those
+// already added. The weight joining the last node to the rest is a cut, and
+// the last two nodes are returned so they can be merged.
+fn minimum_cut_phase(
+    weights: &[HashMap<usize, usize>],
+    active: &[usize],
+    len: usize,
+) -> (usize, usize, usize) {
+    let mut added = vec![false; len];
+    let mut connection = vec![0; len];
+    let mut heap = BinaryHeap::from([(0, active[0])]);
+    let (mut s, mut t) = (active[0], active[0]);
+
+    while let Some((weight, node)) = heap.pop() {
+        if added[node] || weight < connection[node] {
+            continue;
+        }
+        added[node] = true;
+        (s, t) = (t, node);
+        for (&next, &w) in &weights[node] {
+            if !added[next] {
+                connection[next] += w;
+                heap.push((connection[next], next));
+            }
+        }
+    }
+    (s, t, connection[t])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_two_triangles() {
+        let edges = [(1, 2), (2, 3), (3, 1), (4, 5), (5, 6), (6, 4), (3, 4)];
+        let cut = min_cut(edges).unwrap();
+        assert_eq!(cut.edges, vec![(3, 4)]);
+        assert_eq!(cut.sizes.0 * cut.sizes.1, 9);
+    }
+
+    #[test]
+    fn test_day_25_example() {
+        let input = "jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr";
+        let edges = input.lines().flat_map(|line| {
+            let (from, to) = line.split_once(": ").unwrap();
+            to.split(' ').map(move |to| (from, to))
+        });
+
+        let cut = min_cut(edges).unwrap();
+        let mut cut_edges: Vec<(&str, &str)> = cut
+            .edges
+            .into_iter()
+            .map(|(a, b)| (a.min(b), a.max(b)))
+            .collect();
+        cut_edges.sort();
+        assert_eq!(
+            cut_edges,
+            vec![("bvb", "cmg"), ("hfx", "pzl"), ("jqt", "nvd")]
+        );
+        assert_eq!(cut.sizes.0 * cut.sizes.1, 54);
+    }
+
+    #[test]
+    fn test_too_small() {
+        assert_eq!(min_cut(Vec::<(u8, u8)>::new()), None);
+        assert_eq!(min_cut([(1, 1)]), None);
+    }
+
+    #[test]
+    fn test_disconnected() {
+        let cut = min_cut([(1, 2), (2, 3), (4, 5)]).unwrap();
+        assert!(cut.edges.is_empty());
+        assert_eq!(cut.sizes, (3, 2));
+    }
+}
@@ -0,0 +1,164 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap},
+    hash::Hash,
+};
+
+// A min-priority queue that holds each node at most once. Changing or removing
+// a node's priority leaves its old heap entry in place, and `pop` skips those
+// stale entries when it reaches them.
+#[derive(Debug, Clone)]
+pub struct PriorityQueue<N, C> {
+    heap: BinaryHeap<Frontier<N, C>>,
+    priorities: HashMap<N, C>,
+}
+
+impl<N, C> PriorityQueue<N, C>
+where
+    N: Hash + Eq + Clone,
+    C: Ord + Copy,
+{
+    pub fn new() -> PriorityQueue<N, C> {
+        PriorityQueue {
+            heap: BinaryHeap::new(),
+            priorities: HashMap::new(),
+        }
+    }
+
+    // How many nodes are queued, not counting stale entries
+    pub fn len(&self) -> usize {
+        self.priorities.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.priorities.is_empty()
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.priorities.contains_key(node)
+    }
+
+    pub fn priority(&self, node: &N) -> Option<C> {
+        self.priorities.get(node).copied()
+    }
+
+    // Queues `node`, replacing any priority it already had
+    pub fn push(&mut self, node: N, priority: C) {
+        self.priorities.insert(node.clone(), priority);
+        self.heap.push(Frontier {
+            cost: priority,
+            node,
+        });
+    }
+
+    // Queues `node` unless it is already queued at the same or a lower
+    // priority. Returns whether it was queued.
+    pub fn decrease_key(&mut self, node: N, priority: C) -> bool {
+        if self.priority(&node).is_some_and(|known| known <= priority) {
+            return false;
+        }
+        self.push(node, priority);
+        true
+    }
+
+    pub fn remove(&mut self, node: &N) -> Option<C> {
+        self.priorities.remove(node)
+    }
+
+    // The node with the lowest priority. Ties come out in no particular order.
+    pub fn pop(&mut self) -> Option<(N, C)> {
+        while let Some(Frontier { cost, node }) = self.heap.pop() {
+            if self.priorities.get(&node) == Some(&cost) {
+                self.priorities.remove(&node);
+                return Some((node, cost));
+            }
+        }
+        None
+    }
+}
+
+impl<N, C> Default for PriorityQueue<N, C>
+where
+    N: Hash + Eq + Clone,
+    C: Ord + Copy,
+{
+    fn default() -> PriorityQueue<N, C> {
+        PriorityQueue::new()
+    }
+}
+
+// Orders the heap by cost alone, cheapest first, so nodes need not be Ord
+#[derive(Debug, Clone)]
+struct Frontier<N, C> {
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> Ord for Frontier<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.cmp(&self.cost)
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Frontier<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for Frontier<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cost == other.cost
+    }
+}
+
+impl<N, C: Ord> Eq for Frontier<N, C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pops_cheapest_first() {
+        let mut queue = PriorityQueue::new();
+        queue.push("c", 3);
+        queue.push("a", 1);
+        queue.push("b", 2);
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.pop(), Some(("a", 1)));
+        assert_eq!(queue.pop(), Some(("b", 2)));
+        assert_eq!(queue.pop(), Some(("c", 3)));
+        assert_eq!(queue.pop(), None);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_decrease_key() {
+        let mut queue = PriorityQueue::new();
+        queue.push('a', 5);
+        queue.push('b', 3);
+        assert!(queue.decrease_key('a', 1));
+        assert!(!queue.decrease_key('b', 4));
+        assert!(queue.decrease_key('c', 2));
+
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.priority(&'b'), Some(3));
+        assert_eq!(queue.pop(), Some(('a', 1)));
+        assert_eq!(queue.pop(), Some(('c', 2)));
+        assert_eq!(queue.pop(), Some(('b', 3)));
+        // The stale entry for 'a' at 5 is skipped
+        assert_eq!(queue.pop(), None);
+    }
+
+    #[test]
+    fn test_push_can_raise_and_remove_drops() {
+        let mut queue = PriorityQueue::new();
+        queue.push(1, 1);
+        queue.push(2, 2);
+        queue.push(1, 10);
+        assert_eq!(queue.remove(&2), Some(2));
+        assert!(!queue.contains(&2));
+        assert_eq!(queue.pop(), Some((1, 10)));
+        assert_eq!(queue.pop(), None);
+    }
+}
@@ -0,0 +1,203 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use crate::PriorityQueue;
+
+// Everything a search reached from its start. The start has a distance of
+// zero and no predecessor.
+#[derive(Debug, Clone)]
+pub struct Search<N, C> {
+    pub distances: HashMap<N, C>,
+    pub predecessors: HashMap<N, N>,
+}
+
+impl<N, C> Search<N, C>
+where
+    N: Hash + Eq + Clone,
+{
+    pub(crate) fn new(start: N, zero: C) -> Search<N, C> {
+        Search {
+            distances: HashMap::from([(start, zero)]),
+            predecessors: HashMap::new(),
+        }
+    }
+
+    pub fn distance(&self, node: &N) -> Option<&C> {
+        self.distances.get(node)
+    }
+
+    // The nodes from the start to `target`, or None if it was never reached
+    pub fn path_to(&self, target: &N) -> Option<Vec<N>> {
+        if !self.distances.contains_key(target) {
+            return None;
+        }
+        let mut path = vec![target.clone()];
+        while let Some(previous) = self.predecessors.get(path.last()?) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+// Distances count edges, so every node is reached by a shortest path
+pub fn bfs<N, F, I>(start: N, mut neighbors: F) -> Search<N, usize>
+where
+    N: Hash + Eq + Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new(start.clone(), 0);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((node, distance)) = queue.pop_front() {
+        for next in neighbors(&node) {
+            if search.distances.contains_key(&next) {
+                continue;
+            }
+            search.distances.insert(next.clone(), distance + 1);
+            search.predecessors.insert(next.clone(), node.clone());
+            queue.push_back((next, distance + 1));
+        }
+    }
+    search
+}
+
+// Distances are depths in the order the nodes were explored, which are not
+// shortest paths. Use it when only reachability or the visiting order matters.
+pub fn dfs<N, F, I>(start: N, mut neighbors: F) -> Search<N, usize>
+where
+    N: Hash + Eq + Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search {
+        distances: HashMap::new(),
+        predecessors: HashMap::new(),
+    };
+    let mut stack = vec![(start, 0, None)];
+
+    while let Some((node, depth, previous)) = stack.pop() {
+        if search.distances.contains_key(&node) {
+            continue;
+        }
+        search.distances.insert(node.clone(), depth);
+        if let Some(previous) = previous {
+            search.predecessors.insert(node.clone(), previous);
+        }
+        for next in neighbors(&node) {
+            if !search.distances.contains_key(&next) {
+                stack.push((next, depth + 1, Some(node.clone())));
+            }
+        }
+    }
+    search
+}
+
+// `neighbors` gives each next node with the cost of the edge to it. Costs
+// must not be negative.
+pub fn dijkstra<N, C, F, I>(start: N, mut neighbors: F) -> Search<N, C>
+where
+    N: Hash + Eq + Clone,
+    C: Ord + Copy + Default + Add<Output = C>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut search = Search::new(start.clone(), C::default());
+    let mut frontier = PriorityQueue::new();
+    frontier.push(start, C::default());
+
+    while let Some((node, cost)) = frontier.pop() {
+        for (next, edge) in neighbors(&node) {
+            let next_cost = cost + edge;
+            if search
+                .distances
+                .get(&next)
+                .is_some_and(|&known| known <= next_cost)
+            {
+                continue;
+            }
+            search.distances.insert(next.clone(), next_cost);
+            search.predecessors.insert(next.clone(), node.clone());
+            frontier.push(next, next_cost);
+        }
+    }
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -> 1 -> 3 -> 4
+    //  \-> 2 --^
+    // with 5 unreachable
+    fn edges(node: &u32) -> Vec<(u32, u32)> {
+        match node {
+            0 => vec![(1, 1), (2, 5)],
+            1 => vec![(3, 10)],
+            2 => vec![(3, 1)],
+            3 => vec![(4, 1)],
+            5 => vec![(0, 1)],
+            _ => vec![],
+        }
+    }
+
+    fn unweighted(node: &u32) -> Vec<u32> {
+        edges(node).into_iter().map(|(next, _)| next).collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let search = bfs(0, unweighted);
+        assert_eq!(search.distances.len(), 5);
+        assert_eq!(search.distance(&3), Some(&2));
+        assert_eq!(search.distance(&4), Some(&3));
+        assert_eq!(search.distance(&5), None);
+        assert_eq!(search.path_to(&4), Some(vec![0, 1, 3, 4]));
+        assert_eq!(search.path_to(&0), Some(vec![0]));
+        assert_eq!(search.path_to(&5), None);
+    }
+
+    #[test]
+    fn test_dfs() {
+        let search = dfs(0, unweighted);
+        let mut reached: Vec<u32> = search.distances.keys().copied().collect();
+        reached.sort();
+        assert_eq!(reached, vec![0, 1, 2, 3, 4]);
+        // The last neighbour is explored first, so 3 is found through 2
+        assert_eq!(search.path_to(&4), Some(vec![0, 2, 3, 4]));
+        assert_eq!(search.distance(&4), Some(&3));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let search = dijkstra(0, edges);
+        assert_eq!(search.distance(&1), Some(&1));
+        assert_eq!(search.distance(&3), Some(&6));
+        assert_eq!(search.distance(&4), Some(&7));
+        assert_eq!(search.path_to(&4), Some(vec![0, 2, 3, 4]));
+        assert_eq!(search.path_to(&5), None);
+    }
+
+    #[test]
+    fn test_dijkstra_on_a_grid() {
+        // Moving right costs 1 and down costs 2 on a 3x3 grid
+        let search = dijkstra((0, 0), |&(x, y): &(u32, u32)| {
+            let mut next = Vec::new();
+            if x < 2 {
+                next.push(((x + 1, y), 1));
+            }
+            if y < 2 {
+                next.push(((x, y + 1), 2));
+            }
+            next
+        });
+        assert_eq!(search.distances.len(), 9);
+        assert_eq!(search.distance(&(2, 2)), Some(&6));
+        assert_eq!(search.path_to(&(2, 2)).map(|path| path.len()), Some(5));
+    }
+}
@@ -0,0 +1,159 @@
+use crate::Point;
+
+// A set of points on a width x height grid with one bit per cell, for visited
+// sets in searches where a HashSet of points is the slow part
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GridBitSet {
+    width: usize,
+    height: usize,
+    words: Vec<u64>,
+}
+
+impl GridBitSet {
+    pub fn new(width: usize, height: usize) -> GridBitSet {
+        GridBitSet {
+            width,
+            height,
+            words: vec![0; (width * height).div_ceil(64)],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    // How many points are in the set
+    pub fn len(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        let (word, bit) = self.locate(point);
+        self.words[word] & bit != 0
+    }
+
+    // False if the point was already in the set
+    pub fn insert(&mut self, point: Point) -> bool {
+        let (word, bit) = self.locate(point);
+        let added = self.words[word] & bit == 0;
+        self.words[word] |= bit;
+        added
+    }
+
+    // False if the point wasn't in the set
+    pub fn remove(&mut self, point: Point) -> bool {
+        let (word, bit) = self.locate(point);
+        let removed = self.words[word] & bit != 0;
+        self.words[word] &= !bit;
+        removed
+    }
+
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    // Adds every point of `other`, which must be the same size
+    pub fn union_with(&mut self, other: &GridBitSet) {
+        assert_eq!(
+            (self.width, self.height),
+            (other.width, other.height),
+            "Only sets of the same size can be combined"
+        );
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word |= other;
+        }
+    }
+
+    // The points in the set, row by row
+    pub fn iter(&self) -> impl Iterator<Item = Point> + '_ {
+        self.words.iter().enumerate().flat_map(move |(i, &word)| {
+            let mut bits = word;
+            std::iter::from_fn(move || {
+                if bits == 0 {
+                    return None;
+                }
+                let bit = bits.trailing_zeros() as usize;
+                bits &= bits - 1;
+                Some(Point::from_index(i * 64 + bit, self.width))
+            })
+        })
+    }
+
+    // Panics on points off the grid, which would otherwise wrap onto the
+    // next row
+    fn locate(&self, point: Point) -> (usize, u64) {
+        let (width, height) = (self.width, self.height);
+        assert!(
+            point.x < width && point.y < height,
+            "({}, {}) is outside the {width}x{height} grid",
+            point.x,
+            point.y
+        );
+        let index = point.to_index(width);
+        (index / 64, 1 << (index % 64))
+    }
+}
+
+impl Extend<Point> for GridBitSet {
+    fn extend<I: IntoIterator<Item = Point>>(&mut self, points: I) {
+        for point in points {
+            self.insert(point);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_contains_remove() {
+        let mut set = GridBitSet::new(10, 10);
+        assert!(set.is_empty());
+        assert!(set.insert(Point::new(3, 4)));
+        assert!(!set.insert(Point::new(3, 4)));
+        assert!(set.insert(Point::new(9, 9)));
+        assert!(set.contains(Point::new(3, 4)));
+        assert!(!set.contains(Point::new(4, 3)));
+        assert_eq!(set.len(), 2);
+
+        assert!(set.remove(Point::new(3, 4)));
+        assert!(!set.remove(Point::new(3, 4)));
+        assert_eq!(set.len(), 1);
+        set.clear();
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_iter_and_union() {
+        // Wide enough for rows to cross word boundaries
+        let mut a = GridBitSet::new(70, 3);
+        a.extend([Point::new(69, 0), Point::new(0, 2)]);
+        let mut b = GridBitSet::new(70, 3);
+        b.extend([Point::new(1, 1), Point::new(69, 0)]);
+
+        a.union_with(&b);
+        assert_eq!(
+            a.iter().collect::<Vec<_>>(),
+            vec![Point::new(69, 0), Point::new(1, 1), Point::new(0, 2)]
+        );
+        assert_eq!(a.len(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "outside the 3x2 grid")]
+    fn test_outside_panics() {
+        GridBitSet::new(3, 2).insert(Point::new(3, 0));
+    }
+}
@@ -0,0 +1,112 @@
+use std::collections::HashSet;
+
+use crate::{Grid, Point};
+
+// Which cells count as touching: those sharing an edge, or corners as well
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Connectivity {
+    Four,
+    Eight,
+}
+
+// The cells a flood fill reached
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Region {
+    pub cells: HashSet<Point>,
+    // Cells next to the region that stopped it spreading
+    pub boundary: HashSet<Point>,
+    // Whether the region reaches the outside edge of the grid, e.g. so it
+    // isn't enclosed by a loop
+    pub touches_edge: bool,
+}
+
+impl<T> Grid<T> {
+    // Every cell connected to `start` through cells that `predicate` accepts.
+    // The region is empty if `start` itself isn't accepted.
+    pub fn flood_fill<F>(
+        &self,
+        start: Point,
+        connectivity: Connectivity,
+        mut predicate: F,
+    ) -> Region
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut region = Region::default();
+        if !predicate(&self[start]) {
+            return region;
+        }
+        region.cells.insert(start);
+        let mut stack = vec![start];
+        while let Some(point) = stack.pop() {
+            region.touches_edge |= self.is_edge(point);
+            let neighbors: Vec<Point> = match connectivity {
+                Connectivity::Four => self.neighbors4(point).collect(),
+                Connectivity::Eight => self.neighbors8(point).collect(),
+            };
+            for next in neighbors {
+                if region.cells.contains(&next) || region.boundary.contains(&next) {
+                    continue;
+                }
+                if predicate(&self[next]) {
+                    region.cells.insert(next);
+                    stack.push(next);
+                } else {
+                    region.boundary.insert(next);
+                }
+            }
+        }
+        region
+    }
+
+    fn is_edge(&self, point: Point) -> bool {
+        point.x == 0 || point.y == 0 || point.x + 1 == self.width() || point.y + 1 == self.height()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(input: &str) -> Grid<char> {
+        input.parse().unwrap()
+    }
+
+    #[test]
+    fn test_enclosed_and_outside() {
+        let grid = grid("......\n.####.\n.#..#.\n.####.\n......");
+
+        let inside = grid.flood_fill(Point::new(2, 2), Connectivity::Four, |&c| c == '.');
+        assert_eq!(
+            inside.cells,
+            HashSet::from([Point::new(2, 2), Point::new(3, 2)])
+        );
+        assert_eq!(inside.boundary.len(), 6);
+        assert!(!inside.touches_edge);
+
+        let outside = grid.flood_fill(Point::new(0, 0), Connectivity::Four, |&c| c == '.');
+        assert_eq!(outside.cells.len(), 18);
+        assert!(outside.touches_edge);
+        assert!(outside.cells.is_disjoint(&inside.cells));
+    }
+
+    #[test]
+    fn test_connectivity() {
+        // The walls only join up through their corners
+        let grid = grid("#..\n.#.\n..#");
+        let four = grid.flood_fill(Point::new(0, 0), Connectivity::Four, |&c| c == '#');
+        let eight = grid.flood_fill(Point::new(0, 0), Connectivity::Eight, |&c| c == '#');
+
+        assert_eq!(four.cells, HashSet::from([Point::new(0, 0)]));
+        assert_eq!(four.boundary.len(), 2);
+        assert_eq!(eight.cells.len(), 3);
+        assert_eq!(eight.boundary.len(), 6);
+    }
+
+    #[test]
+    fn test_start_not_accepted() {
+        let grid = grid("#.\n..");
+        let region = grid.flood_fill(Point::new(0, 0), Connectivity::Four, |&c| c == '.');
+        assert_eq!(region, Region::default());
+    }
+}
@@ -0,0 +1,585 @@
+use std::{
+    fmt,
+    iter::StepBy,
+    ops::{Index, IndexMut},
+    slice::{ChunksExact, ChunksExactMut, Iter},
+    str::FromStr,
+};
+
+use anyhow::{bail, ensure, Context, Error, Result};
+
+use crate::{Direction, Point, Vec2};
+
+// A rectangle of cells stored row by row in one Vec. `x` runs along a row and
+// `y` down the rows, so (0, 0) is the first char of the first line of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    // Panics on an empty grid, which from_vec would reject
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        assert!(width > 0, "A grid needs at least one column");
+        assert!(height > 0, "A grid needs at least one row");
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    // Rows become columns, so (x, y) moves to (y, x)
+    pub fn transpose(&self) -> Grid<T> {
+        let cells = self.columns().flat_map(|column| column.cloned()).collect();
+        self.turned(cells)
+    }
+
+    // The bottom of each column becomes the start of its row
+    pub fn rotate_clockwise(&self) -> Grid<T> {
+        let cells = self
+            .columns()
+            .flat_map(|column| column.rev().cloned())
+            .collect();
+        self.turned(cells)
+    }
+
+    // The last column becomes the first row
+    pub fn rotate_counter_clockwise(&self) -> Grid<T> {
+        let cells = self
+            .columns()
+            .rev()
+            .flat_map(|column| column.cloned())
+            .collect();
+        self.turned(cells)
+    }
+
+    // Mirrors each row, swapping the left and right edges
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        let cells = self
+            .rows()
+            .flat_map(|row| row.iter().rev().cloned())
+            .collect();
+        Grid { cells, ..*self }
+    }
+
+    // Reverses the order of the rows, swapping the top and bottom edges
+    pub fn flip_vertical(&self) -> Grid<T> {
+        let cells = self.rows().rev().flat_map(|row| row.to_vec()).collect();
+        Grid { cells, ..*self }
+    }
+
+    // Every cell that differs from `other`, with this grid's value then
+    // `other`'s, row by row. Panics if the grids aren't the same size.
+    pub fn diff(&self, other: &Grid<T>) -> Vec<(Point, T, T)>
+    where
+        T: PartialEq,
+    {
+        assert_eq!(
+            (self.width, self.height),
+            (other.width, other.height),
+            "Only grids of the same size can be compared"
+        );
+        self.enumerate()
+            .zip(&other.cells)
+            .filter(|((_, before), after)| before != after)
+            .map(|((point, before), after)| (point, before.clone(), after.clone()))
+            .collect()
+    }
+
+    fn turned(&self, cells: Vec<T>) -> Grid<T> {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    // The cells of each row in turn
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Result<Grid<T>> {
+        ensure!(width > 0, "A grid needs at least one column");
+        // Turning a grid without rows would leave it without columns
+        ensure!(!cells.is_empty(), "A grid needs at least one row");
+        ensure!(
+            cells.len().is_multiple_of(width),
+            "{} cells do not fill rows of {width}",
+            cells.len()
+        );
+        Ok(Grid {
+            width,
+            height: cells.len() / width,
+            cells,
+        })
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>> {
+        let width = rows.first().map_or(0, Vec::len);
+        if let Some(y) = rows.iter().position(|row| row.len() != width) {
+            bail!(
+                "Row {y} has {} cells but the first has {width}",
+                rows[y].len()
+            );
+        }
+        Grid::from_vec(width, rows.into_iter().flatten().collect())
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.contains(x, y).then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.contains(x, y)
+            .then(|| &mut self.cells[y * self.width + x])
+    }
+
+    // Returns the cell that was replaced. Panics outside the grid, like
+    // indexing.
+    pub fn set(&mut self, x: usize, y: usize, value: T) -> T {
+        std::mem::replace(&mut self[(x, y)], value)
+    }
+
+    pub fn rows(&self) -> ChunksExact<'_, T> {
+        self.cells.chunks_exact(self.width)
+    }
+
+    pub fn rows_mut(&mut self) -> ChunksExactMut<'_, T> {
+        self.cells.chunks_exact_mut(self.width)
+    }
+
+    // Each column read from top to bottom, reversible to read it upwards
+    pub fn columns(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = StepBy<Iter<'_, T>>> + ExactSizeIterator {
+        (0..self.width).map(move |x| self.cells[x..].iter().step_by(self.width))
+    }
+
+    // Every cell with its position, row by row
+    pub fn enumerate(&self) -> impl Iterator<Item = (Point, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| (Point::from_index(i, width), cell))
+    }
+
+    // The neighbouring cell in `direction`, if it is still on the grid
+    pub fn step(&self, point: Point, direction: Direction) -> Option<Point> {
+        self.offset(point, direction.delta().x, direction.delta().y)
+    }
+
+    pub fn offset(&self, point: Point, dx: isize, dy: isize) -> Option<Point> {
+        point
+            .checked_add_signed(Vec2::new(dx, dy))
+            .filter(|next| self.contains(next.x, next.y))
+    }
+
+    // The up to four cells sharing an edge with `point`, clockwise from north
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbors_by(point, Direction::iter().map(Direction::delta))
+    }
+
+    // The up to eight cells sharing an edge or a corner with `point`,
+    // clockwise from north
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        let deltas = Direction::iter().flat_map(|direction| {
+            [
+                direction.delta(),
+                direction.delta() + direction.turn_right().delta(),
+            ]
+        });
+        self.neighbors_by(point, deltas)
+    }
+
+    fn neighbors_by(
+        &self,
+        point: Point,
+        deltas: impl Iterator<Item = Vec2> + 'static,
+    ) -> impl Iterator<Item = Point> + '_ {
+        deltas.filter_map(move |delta| self.offset(point, delta.x, delta.y))
+    }
+
+    // Every cell, row by row, for when the layout does not matter
+    pub fn as_slice(&self) -> &[T] {
+        &self.cells
+    }
+
+    // Lets whole rows be handed out at once, e.g. to rayon's par_chunks_mut
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        let (width, height) = (self.width, self.height);
+        self.get(x, y)
+            .unwrap_or_else(|| panic!("({x}, {y}) is outside the {width}x{height} grid"))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("({x}, {y}) is outside the {width}x{height} grid"))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        &self[(point.x, point.y)]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        &mut self[(point.x, point.y)]
+    }
+}
+
+impl Grid<u8> {
+    // A grid of single digits, such as a heat loss map. Parsing a Grid<u8>
+    // with FromStr would give the chars' byte values instead.
+    pub fn from_digits(s: &str) -> Result<Grid<u8>> {
+        let rows = grid_lines(s)?
+            .into_iter()
+            .enumerate()
+            .map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(x, c)| match c.to_digit(10) {
+                        Some(digit) => Ok(digit as u8),
+                        None => bail!("Invalid digit {c:?} at ({x}, {y})"),
+                    })
+                    .collect::<Result<Vec<_>>>()
+            })
+            .collect::<Result<Vec<_>>>()?;
+        ensure!(!rows.is_empty(), "There is no grid to parse");
+        Grid::from_rows(rows)
+    }
+}
+
+// The grid's rows, ignoring any blank lines after the last. A blank line
+// between rows would join two grids into one, so it is an error.
+fn grid_lines(s: &str) -> Result<Vec<&str>> {
+    let mut lines: Vec<&str> = s.lines().collect();
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+    if let Some(y) = lines.iter().position(|line| line.is_empty()) {
+        bail!("Blank line at row {y} inside the grid");
+    }
+    Ok(lines)
+}
+
+// One line per row and one char per cell, as the puzzles are given
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+    T::Error: Into<Error>,
+{
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Grid<T>> {
+        let rows = grid_lines(s)?
+            .into_iter()
+            .enumerate()
+            .map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(x, c)| {
+                        T::try_from(c)
+                            .map_err(Into::into)
+                            .with_context(|| format!("Invalid {c:?} at ({x}, {y})"))
+                    })
+                    .collect::<Result<Vec<_>>>()
+            })
+            .collect::<Result<Vec<_>>>()?;
+        ensure!(!rows.is_empty(), "There is no grid to parse");
+        Grid::from_rows(rows)
+    }
+}
+
+// The same layout FromStr reads, so parsing the output gives the grid back
+impl<T> fmt::Display for Grid<T>
+where
+    T: Clone + Into<char>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            let line: String = row.iter().cloned().map(Into::into).collect();
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Cell {
+        Open,
+        Wall,
+    }
+
+    impl TryFrom<char> for Cell {
+        type Error = Error;
+
+        fn try_from(c: char) -> Result<Cell> {
+            match c {
+                '.' => Ok(Cell::Open),
+                '#' => Ok(Cell::Wall),
+                _ => bail!("Not a cell"),
+            }
+        }
+    }
+
+    impl From<Cell> for char {
+        fn from(cell: Cell) -> char {
+            match cell {
+                Cell::Open => '.',
+                Cell::Wall => '#',
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_grid() {
+        let grid: Grid<Cell> = ".#.\n##.\n".parse().unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 0)], Cell::Wall);
+        assert_eq!(grid.get(2, 1), Some(&Cell::Open));
+        assert_eq!(grid.get(3, 1), None);
+        assert_eq!(grid.get(0, 2), None);
+    }
+
+    #[test]
+    fn test_display_roundtrip() {
+        let input = ".#.\n##.\n";
+        let grid: Grid<Cell> = input.parse().unwrap();
+        assert_eq!(grid.to_string(), input);
+        assert_eq!(grid.to_string().parse::<Grid<Cell>>().unwrap(), grid);
+        assert_eq!(grid.rotate_clockwise().to_string(), "#.\n##\n..\n");
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_grids() {
+        assert!("..\n.".parse::<Grid<Cell>>().is_err());
+        assert!(".x".parse::<Grid<Cell>>().is_err());
+        assert!("".parse::<Grid<Cell>>().is_err());
+    }
+
+    #[test]
+    fn test_from_str_blank_lines() {
+        let grid = "#.\n.#\n\n\n".parse::<Grid<Cell>>().unwrap();
+        assert_eq!(grid.height(), 2);
+
+        let error = "#.\n.#\n\n##\n..".parse::<Grid<Cell>>().unwrap_err();
+        assert_eq!(error.to_string(), "Blank line at row 2 inside the grid");
+        let error = "\n#.\n.x".parse::<Grid<Cell>>().unwrap_err();
+        assert_eq!(error.to_string(), "Blank line at row 0 inside the grid");
+    }
+
+    #[test]
+    fn test_from_digits() {
+        let grid = Grid::from_digits("241\r\n321\r\n").unwrap();
+        assert_eq!(
+            grid,
+            Grid::from_rows(vec![vec![2, 4, 1], vec![3, 2, 1]]).unwrap()
+        );
+
+        let error = Grid::from_digits("12\n3-").unwrap_err();
+        assert_eq!(error.to_string(), "Invalid digit '-' at (1, 1)");
+        assert!(Grid::from_digits("12\n3").is_err());
+        assert!(Grid::from_digits("\n").is_err());
+        assert!(Grid::from_digits("12\n\n34").is_err());
+    }
+
+    #[test]
+    fn test_set_and_get_mut() {
+        let mut grid = Grid::new(2, 2, 0);
+        assert_eq!(grid.set(1, 0, 5), 0);
+        *grid.get_mut(0, 1).unwrap() += 3;
+
+        assert_eq!(grid.as_slice(), &[0, 5, 3, 0]);
+        assert!(grid.get_mut(2, 0).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "outside the 2x2 grid")]
+    fn test_set_outside_panics() {
+        Grid::new(2, 2, 0).set(0, 2, 1);
+    }
+
+    #[test]
+    fn test_rows() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+        for row in grid.rows_mut() {
+            row.reverse();
+        }
+
+        let rows: Vec<_> = grid.rows().collect();
+        assert_eq!(rows, vec![&[2, 1], &[4, 3], &[6, 5]]);
+    }
+
+    #[test]
+    fn test_step_and_offset() {
+        let grid = Grid::new(3, 2, 0);
+        let corner = Point::new(0, 0);
+        assert_eq!(grid.step(corner, Direction::East), Some(Point::new(1, 0)));
+        assert_eq!(grid.step(corner, Direction::South), Some(Point::new(0, 1)));
+        assert_eq!(grid.step(corner, Direction::North), None);
+        assert_eq!(grid.step(corner, Direction::West), None);
+        assert_eq!(grid.step(Point::new(2, 1), Direction::East), None);
+
+        assert_eq!(grid.offset(corner, 2, 1), Some(Point::new(2, 1)));
+        assert_eq!(grid.offset(corner, 3, 0), None);
+        assert_eq!(grid.offset(Point::new(2, 1), -2, -1), Some(corner));
+        assert_eq!(grid.offset(Point::new(2, 1), -3, 0), None);
+    }
+
+    #[test]
+    fn test_columns() {
+        let grid = Grid::from_rows(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+        let columns: Vec<Vec<i32>> = grid.columns().map(|c| c.copied().collect()).collect();
+        assert_eq!(columns, vec![vec![1, 3, 5], vec![2, 4, 6]]);
+
+        let upwards: Vec<i32> = grid.columns().nth(1).unwrap().rev().copied().collect();
+        assert_eq!(upwards, vec![6, 4, 2]);
+    }
+
+    #[test]
+    fn test_enumerate() {
+        let grid = Grid::from_rows(vec![vec!['a', 'b'], vec!['c', 'd']]).unwrap();
+        let cells: Vec<(Point, char)> = grid.enumerate().map(|(p, &c)| (p, c)).collect();
+        assert_eq!(
+            cells,
+            vec![
+                (Point::new(0, 0), 'a'),
+                (Point::new(1, 0), 'b'),
+                (Point::new(0, 1), 'c'),
+                (Point::new(1, 1), 'd'),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::new(3, 2, 0);
+        let middle: Vec<Point> = grid.neighbors4(Point::new(1, 0)).collect();
+        assert_eq!(
+            middle,
+            vec![Point::new(2, 0), Point::new(1, 1), Point::new(0, 0)]
+        );
+
+        let corner: Vec<Point> = grid.neighbors8(Point::new(2, 1)).collect();
+        assert_eq!(
+            corner,
+            vec![Point::new(2, 0), Point::new(1, 1), Point::new(1, 0)]
+        );
+        assert_eq!(grid.neighbors8(Point::new(1, 0)).count(), 5);
+    }
+
+    // 1 2 3
+    // 4 5 6
+    fn two_by_three() -> Grid<i32> {
+        Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap()
+    }
+
+    #[test]
+    fn test_transpose() {
+        let expected = Grid::from_rows(vec![vec![1, 4], vec![2, 5], vec![3, 6]]).unwrap();
+        assert_eq!(two_by_three().transpose(), expected);
+        assert_eq!(two_by_three().transpose().transpose(), two_by_three());
+    }
+
+    #[test]
+    fn test_rotate() {
+        let clockwise = Grid::from_rows(vec![vec![4, 1], vec![5, 2], vec![6, 3]]).unwrap();
+        let counter_clockwise = Grid::from_rows(vec![vec![3, 6], vec![2, 5], vec![1, 4]]).unwrap();
+        assert_eq!(two_by_three().rotate_clockwise(), clockwise);
+        assert_eq!(two_by_three().rotate_counter_clockwise(), counter_clockwise);
+        assert_eq!(clockwise.rotate_counter_clockwise(), two_by_three());
+
+        let mut grid = two_by_three();
+        for _ in 0..4 {
+            grid = grid.rotate_clockwise();
+        }
+        assert_eq!(grid, two_by_three());
+    }
+
+    #[test]
+    fn test_flip() {
+        let horizontal = Grid::from_rows(vec![vec![3, 2, 1], vec![6, 5, 4]]).unwrap();
+        let vertical = Grid::from_rows(vec![vec![4, 5, 6], vec![1, 2, 3]]).unwrap();
+        assert_eq!(two_by_three().flip_horizontal(), horizontal);
+        assert_eq!(two_by_three().flip_vertical(), vertical);
+        assert_eq!(
+            two_by_three().flip_horizontal().flip_vertical(),
+            two_by_three().rotate_clockwise().rotate_clockwise()
+        );
+    }
+
+    #[test]
+    fn test_diff() {
+        let before: Grid<Cell> = ".#.\n##.\n".parse().unwrap();
+        let after: Grid<Cell> = ".##\n.#.\n".parse().unwrap();
+        assert_eq!(
+            before.diff(&after),
+            vec![
+                (Point::new(2, 0), Cell::Open, Cell::Wall),
+                (Point::new(0, 1), Cell::Wall, Cell::Open),
+            ]
+        );
+        assert!(before.diff(&before).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "same size")]
+    fn test_diff_needs_same_size() {
+        two_by_three().diff(&two_by_three().transpose());
+    }
+
+    #[test]
+    fn test_from_vec_needs_whole_rows() {
+        assert!(Grid::from_vec(2, vec![1, 2, 3]).is_err());
+        assert!(Grid::<u8>::from_vec(0, vec![]).is_err());
+        assert!(Grid::<u8>::from_vec(3, vec![]).is_err());
+        assert_eq!(Grid::from_vec(3, vec![1, 2, 3]).unwrap().height(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one column")]
+    fn test_new_needs_a_column() {
+        Grid::new(0, 3, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one row")]
+    fn test_new_needs_a_row() {
+        Grid::new(3, 0, 0);
+    }
+}
@@ -0,0 +1,17 @@
+mod bitset;
+mod direction;
+mod fill;
+mod grid;
+mod point;
+mod ray;
+mod space;
+mod sparse;
+
+pub use bitset::*;
+pub use direction::*;
+pub use fill::*;
+pub use grid::*;
+pub use point::*;
+pub use ray::*;
+pub use space::*;
+pub use sparse::*;
@@ -0,0 +1,224 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use anyhow::{Error, Result};
+
+// A cell position on a grid, with y growing down the rows
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+// A signed step between two points, or a position that can go negative
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+pub struct Vec2 {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub const fn new(x: usize, y: usize) -> Point {
+        Point { x, y }
+    }
+
+    // None when the result would fall off the top or left edge
+    pub fn checked_add_signed(self, delta: Vec2) -> Option<Point> {
+        Some(Point {
+            x: self.x.checked_add_signed(delta.x)?,
+            y: self.y.checked_add_signed(delta.y)?,
+        })
+    }
+
+    pub fn manhattan(self, other: Point) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    // Where this point lives in a row-major Vec of the given width
+    pub fn to_index(self, width: usize) -> usize {
+        self.y * width + self.x
+    }
+
+    pub fn from_index(index: usize, width: usize) -> Point {
+        Point::new(index % width, index / width)
+    }
+}
+
+impl Vec2 {
+    pub const fn new(x: isize, y: isize) -> Vec2 {
+        Vec2 { x, y }
+    }
+
+    pub fn manhattan(self) -> usize {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Point {
+        Point { x, y }
+    }
+}
+
+impl From<Point> for (usize, usize) {
+    fn from(point: Point) -> (usize, usize) {
+        (point.x, point.y)
+    }
+}
+
+impl From<(isize, isize)> for Vec2 {
+    fn from((x, y): (isize, isize)) -> Vec2 {
+        Vec2 { x, y }
+    }
+}
+
+impl TryFrom<Point> for Vec2 {
+    type Error = Error;
+
+    fn try_from(point: Point) -> Result<Vec2> {
+        Ok(Vec2 {
+            x: point.x.try_into()?,
+            y: point.y.try_into()?,
+        })
+    }
+}
+
+impl TryFrom<Vec2> for Point {
+    type Error = Error;
+
+    fn try_from(vec: Vec2) -> Result<Point> {
+        Ok(Point {
+            x: vec.x.try_into()?,
+            y: vec.y.try_into()?,
+        })
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+// Panics on underflow, the same as subtracting usizes
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<usize> for Point {
+    type Output = Point;
+
+    fn mul(self, scale: usize) -> Point {
+        Point::new(self.x * scale, self.y * scale)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<isize> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, scale: isize) -> Vec2 {
+        Vec2::new(self.x * scale, self.y * scale)
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Vec2 {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, other: Vec2) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Vec2 {
+    fn sub_assign(&mut self, other: Vec2) {
+        *self = *self - other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_arithmetic() {
+        let a = Point::new(3, 4);
+        let b = Point::new(1, 2);
+        assert_eq!(a + b, Point::new(4, 6));
+        assert_eq!(a - b, Point::new(2, 2));
+        assert_eq!(b * 3, Point::new(3, 6));
+        assert_eq!(a.manhattan(b), 4);
+        assert_eq!(b.manhattan(a), 4);
+    }
+
+    #[test]
+    fn test_vec2_arithmetic() {
+        let a = Vec2::new(3, -4);
+        let b = Vec2::new(-1, 2);
+        assert_eq!(a + b, Vec2::new(2, -2));
+        assert_eq!(a - b, Vec2::new(4, -6));
+        assert_eq!(b * -2, Vec2::new(2, -4));
+        assert_eq!(-a, Vec2::new(-3, 4));
+        assert_eq!(a.manhattan(), 7);
+    }
+
+    #[test]
+    fn test_checked_add_signed() {
+        let point = Point::new(1, 0);
+        assert_eq!(
+            point.checked_add_signed(Vec2::new(-1, 2)),
+            Some(Point::new(0, 2))
+        );
+        assert_eq!(point.checked_add_signed(Vec2::new(-2, 0)), None);
+        assert_eq!(point.checked_add_signed(Vec2::new(0, -1)), None);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let point = Point::from_index(7, 3);
+        assert_eq!(point, Point::new(1, 2));
+        assert_eq!(point.to_index(3), 7);
+        assert_eq!(<(usize, usize)>::from(point), (1, 2));
+        assert_eq!(Vec2::try_from(point).unwrap(), Vec2::new(1, 2));
+        assert_eq!(Point::try_from(Vec2::new(1, 2)).unwrap(), point);
+        assert!(Point::try_from(Vec2::new(-1, 2)).is_err());
+    }
+}
@@ -0,0 +1,100 @@
+use std::collections::HashSet;
+
+use crate::{Direction, Grid, Point};
+
+// What a ray does on reaching a cell
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RayAction {
+    Continue,
+    Turn(Direction),
+    // Carries on as two rays, one in each direction
+    Split(Direction, Direction),
+    Stop,
+}
+
+impl<T> Grid<T> {
+    // Follows a ray from `start` heading in `direction`, asking `action` what
+    // to do at every cell it reaches, the start included. Returns each cell
+    // visited with the heading the ray arrived with. A ray ends when it
+    // leaves the grid, stops, or repeats a cell and heading it already took,
+    // so loops are safe.
+    pub fn cast_ray<F>(
+        &self,
+        start: Point,
+        direction: Direction,
+        mut action: F,
+    ) -> HashSet<(Point, Direction)>
+    where
+        F: FnMut(&T, Direction) -> RayAction,
+    {
+        let mut visited = HashSet::from([(start, direction)]);
+        let mut rays = vec![(start, direction)];
+
+        while let Some((point, heading)) = rays.pop() {
+            let headings = match action(&self[point], heading) {
+                RayAction::Continue => [Some(heading), None],
+                RayAction::Turn(turned) => [Some(turned), None],
+                RayAction::Split(a, b) => [Some(a), Some(b)],
+                RayAction::Stop => [None, None],
+            };
+            for heading in headings.into_iter().flatten() {
+                if let Some(next) = self.step(point, heading) {
+                    if visited.insert((next, heading)) {
+                        rays.push((next, heading));
+                    }
+                }
+            }
+        }
+        visited
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // '/' and '\' turn the ray, '+' splits it to both sides and '#' stops it
+    fn mirrors(cell: &char, heading: Direction) -> RayAction {
+        match (cell, heading) {
+            ('/', Direction::North | Direction::South) => RayAction::Turn(heading.turn_right()),
+            ('/', _) => RayAction::Turn(heading.turn_left()),
+            ('\\', Direction::North | Direction::South) => RayAction::Turn(heading.turn_left()),
+            ('\\', _) => RayAction::Turn(heading.turn_right()),
+            ('+', _) => RayAction::Split(heading.turn_left(), heading.turn_right()),
+            ('#', _) => RayAction::Stop,
+            _ => RayAction::Continue,
+        }
+    }
+
+    fn cells(visited: &HashSet<(Point, Direction)>) -> HashSet<Point> {
+        visited.iter().map(|&(point, _)| point).collect()
+    }
+
+    #[test]
+    fn test_straight_and_stop() {
+        let grid: Grid<char> = "..#.\n....\n".parse().unwrap();
+        let visited = grid.cast_ray(Point::new(0, 0), Direction::East, mirrors);
+        assert_eq!(
+            cells(&visited),
+            HashSet::from([Point::new(0, 0), Point::new(1, 0), Point::new(2, 0)])
+        );
+    }
+
+    #[test]
+    fn test_turn_and_split() {
+        let grid: Grid<char> = ".\\.\n.+.\n...\n".parse().unwrap();
+        let visited = grid.cast_ray(Point::new(0, 0), Direction::East, mirrors);
+        // Down the middle column, then out both sides of the splitter
+        assert!(visited.contains(&(Point::new(1, 1), Direction::South)));
+        assert!(visited.contains(&(Point::new(0, 1), Direction::West)));
+        assert!(visited.contains(&(Point::new(2, 1), Direction::East)));
+        assert_eq!(cells(&visited).len(), 5);
+    }
+
+    #[test]
+    fn test_loops_end() {
+        let grid: Grid<char> = "/\\\n\\/\n".parse().unwrap();
+        let visited = grid.cast_ray(Point::new(0, 1), Direction::West, mirrors);
+        assert_eq!(visited.len(), 4);
+    }
+}
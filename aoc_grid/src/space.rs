@@ -0,0 +1,224 @@
+use std::{collections::HashMap, ops::RangeInclusive, str::FromStr};
+
+use anyhow::{anyhow, Context, Error, Result};
+
+// A cube position, with z growing up away from the ground
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: usize,
+    pub y: usize,
+    pub z: usize,
+}
+
+impl Point3 {
+    pub const fn new(x: usize, y: usize, z: usize) -> Point3 {
+        Point3 { x, y, z }
+    }
+}
+
+// "1,0,1"
+impl FromStr for Point3 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Point3> {
+        let coordinates = s
+            .split(',')
+            .map(|c| c.trim().parse())
+            .collect::<Result<Vec<usize>, _>>()
+            .with_context(|| format!("Invalid point {s:?}"))?;
+        match coordinates[..] {
+            [x, y, z] => Ok(Point3::new(x, y, z)),
+            _ => Err(anyhow!("Expected three coordinates, got {s:?}")),
+        }
+    }
+}
+
+// A box of cubes between two corners, both included. The corners are kept
+// sorted so `min` is never above or beyond `max` on any axis.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Brick {
+    min: Point3,
+    max: Point3,
+}
+
+impl Brick {
+    pub fn new(a: Point3, b: Point3) -> Brick {
+        Brick {
+            min: Point3::new(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z)),
+            max: Point3::new(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z)),
+        }
+    }
+
+    pub fn min(&self) -> Point3 {
+        self.min
+    }
+
+    pub fn max(&self) -> Point3 {
+        self.max
+    }
+
+    pub fn bottom(&self) -> usize {
+        self.min.z
+    }
+
+    pub fn top(&self) -> usize {
+        self.max.z
+    }
+
+    // Whether the two would hit each other moving straight up or down
+    pub fn overlaps_xy(&self, other: &Brick) -> bool {
+        overlap(self.min.x..=self.max.x, other.min.x..=other.max.x)
+            && overlap(self.min.y..=self.max.y, other.min.y..=other.max.y)
+    }
+
+    pub fn overlaps(&self, other: &Brick) -> bool {
+        self.overlaps_xy(other) && overlap(self.min.z..=self.max.z, other.min.z..=other.max.z)
+    }
+
+    // Whether this brick sits directly on top of `other`
+    pub fn rests_on(&self, other: &Brick) -> bool {
+        other.top() + 1 == self.bottom() && self.overlaps_xy(other)
+    }
+
+    // The same brick moved so its bottom is at `z`
+    pub fn with_bottom(&self, z: usize) -> Brick {
+        let height = self.max.z - self.min.z;
+        Brick {
+            min: Point3 { z, ..self.min },
+            max: Point3 {
+                z: z + height,
+                ..self.max
+            },
+        }
+    }
+
+    pub fn cubes(&self) -> impl Iterator<Item = Point3> + '_ {
+        (self.min.z..=self.max.z).flat_map(move |z| {
+            (self.min.y..=self.max.y)
+                .flat_map(move |y| (self.min.x..=self.max.x).map(move |x| Point3::new(x, y, z)))
+        })
+    }
+}
+
+// "1,0,1~1,2,1"
+impl FromStr for Brick {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Brick> {
+        let (a, b) = s
+            .split_once('~')
+            .ok_or_else(|| anyhow!("Expected \"<corner>~<corner>\", got {s:?}"))?;
+        Ok(Brick::new(a.parse()?, b.parse()?))
+    }
+}
+
+fn overlap(a: RangeInclusive<usize>, b: RangeInclusive<usize>) -> bool {
+    a.start() <= b.end() && b.start() <= a.end()
+}
+
+// Sorts the bricks from the ground up and drops each one until it lands on
+// the ground at z = 0 or on a brick below it. Returns how many of them moved.
+pub fn settle(bricks: &mut [Brick]) -> usize {
+    bricks.sort_by_key(Brick::bottom);
+    // The highest top so far in each column
+    let mut heights: HashMap<(usize, usize), usize> = HashMap::new();
+    let mut moved = 0;
+    for brick in bricks.iter_mut() {
+        let floor = (brick.min.x..=brick.max.x)
+            .flat_map(|x| (brick.min.y..=brick.max.y).map(move |y| (x, y)))
+            .map(|column| heights.get(&column).copied().unwrap_or(0))
+            .max()
+            .unwrap_or(0);
+        if brick.bottom() > floor + 1 {
+            *brick = brick.with_bottom(floor + 1);
+            moved += 1;
+        }
+        for x in brick.min.x..=brick.max.x {
+            for y in brick.min.y..=brick.max.y {
+                heights.insert((x, y), brick.top());
+            }
+        }
+    }
+    moved
+}
+
+// For each brick, the indices of the bricks it rests directly on
+pub fn supports(bricks: &[Brick]) -> Vec<Vec<usize>> {
+    bricks
+        .iter()
+        .map(|brick| {
+            (0..bricks.len())
+                .filter(|&i| brick.rests_on(&bricks[i]))
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Day 22's example snapshot
+    const BRICKS: &str = "1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9";
+
+    fn parse(input: &str) -> Vec<Brick> {
+        input.lines().map(|line| line.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let brick: Brick = "2,2,2~2,2,0".parse().unwrap();
+        assert_eq!(brick.min(), Point3::new(2, 2, 0));
+        assert_eq!(brick.max(), Point3::new(2, 2, 2));
+        assert_eq!(brick.cubes().count(), 3);
+        assert!("1,2~3,4".parse::<Brick>().is_err());
+        assert!("1,2,3".parse::<Brick>().is_err());
+    }
+
+    #[test]
+    fn test_overlaps() {
+        let bricks = parse(BRICKS);
+        // A and B cross when seen from above but are at different heights
+        assert!(bricks[0].overlaps_xy(&bricks[1]));
+        assert!(!bricks[0].overlaps(&bricks[1]));
+        assert!(bricks[1].rests_on(&bricks[0]));
+        // B and C are side by side
+        assert!(!bricks[1].overlaps_xy(&bricks[2]));
+        assert!(bricks[3].overlaps(&bricks[3].with_bottom(4)));
+    }
+
+    #[test]
+    fn test_settle_and_supports() {
+        let mut bricks = parse(BRICKS);
+        assert_eq!(settle(&mut bricks), 5);
+        let bottoms: Vec<usize> = bricks.iter().map(Brick::bottom).collect();
+        assert_eq!(bottoms, vec![1, 2, 2, 3, 3, 4, 5]);
+        // Settling again moves nothing
+        assert_eq!(settle(&mut bricks), 0);
+
+        let supports = supports(&bricks);
+        assert_eq!(
+            supports,
+            vec![
+                vec![],
+                vec![0],
+                vec![0],
+                vec![1, 2],
+                vec![1, 2],
+                vec![3, 4],
+                vec![5],
+            ]
+        );
+        // Removing A or F would make something fall, the rest are safe
+        let safe = (0..bricks.len())
+            .filter(|&i| !supports.iter().any(|below| below == &[i]))
+            .count();
+        assert_eq!(safe, 5);
+    }
+}
@@ -0,0 +1,178 @@
+use std::collections::HashMap;
+
+use crate::{Grid, Point, Vec2};
+
+// Cells at any signed position, for when the puzzle's coordinates are huge or
+// go negative. Only the cells that were set are stored, and the bounds grow
+// and shrink with them. Use `densify` to get a Grid back out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Vec2, T>,
+    bounds: Option<(Vec2, Vec2)>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, position: Vec2) -> bool {
+        self.cells.contains_key(&position)
+    }
+
+    pub fn get(&self, position: Vec2) -> Option<&T> {
+        self.cells.get(&position)
+    }
+
+    pub fn get_mut(&mut self, position: Vec2) -> Option<&mut T> {
+        self.cells.get_mut(&position)
+    }
+
+    // Returns whatever was there before
+    pub fn insert(&mut self, position: Vec2, value: T) -> Option<T> {
+        let corners = self.bounds.into_iter().flat_map(|(min, max)| [min, max]);
+        self.bounds = bounds_of(corners.chain([position]));
+        self.cells.insert(position, value)
+    }
+
+    pub fn remove(&mut self, position: Vec2) -> Option<T> {
+        let value = self.cells.remove(&position)?;
+        // Only a cell on the edge of the bounds can pull them in
+        if let Some((min, max)) = self.bounds {
+            if position.x == min.x
+                || position.y == min.y
+                || position.x == max.x
+                || position.y == max.y
+            {
+                self.bounds = bounds_of(self.cells.keys().copied());
+            }
+        }
+        Some(value)
+    }
+
+    // The smallest and largest x and y of any cell, both included
+    pub fn bounds(&self) -> Option<(Vec2, Vec2)> {
+        self.bounds
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2, &T)> {
+        self.cells
+            .iter()
+            .map(|(&position, value)| (position, value))
+    }
+
+    // A dense copy covering the bounds, with `fill` wherever no cell was set,
+    // and the position that became (0, 0). None if there are no cells.
+    pub fn densify(&self, fill: T) -> Option<(Grid<T>, Vec2)>
+    where
+        T: Clone,
+    {
+        let (min, max) = self.bounds?;
+        let (width, height) = ((max.x - min.x + 1) as usize, (max.y - min.y + 1) as usize);
+        let mut grid = Grid::new(width, height, fill);
+        for (&position, value) in &self.cells {
+            let point = Point::try_from(position - min).expect("Cells should be within the bounds");
+            grid[point] = value.clone();
+        }
+        Some((grid, min))
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> SparseGrid<T> {
+        SparseGrid::new()
+    }
+}
+
+impl<T> FromIterator<(Vec2, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Vec2, T)>>(iter: I) -> SparseGrid<T> {
+        let mut grid = SparseGrid::new();
+        for (position, value) in iter {
+            grid.insert(position, value);
+        }
+        grid
+    }
+}
+
+// Every cell of a dense grid, so it can be extended past its edges
+impl<T: Clone> From<&Grid<T>> for SparseGrid<T> {
+    fn from(grid: &Grid<T>) -> SparseGrid<T> {
+        grid.enumerate()
+            .map(|(point, value)| {
+                let position = Vec2::try_from(point).expect("Grid should fit in isize");
+                (position, value.clone())
+            })
+            .collect()
+    }
+}
+
+fn bounds_of(positions: impl Iterator<Item = Vec2>) -> Option<(Vec2, Vec2)> {
+    positions.fold(None, |bounds, position| {
+        Some(match bounds {
+            Some((min, max)) => (
+                Vec2::new(min.x.min(position.x), min.y.min(position.y)),
+                Vec2::new(max.x.max(position.x), max.y.max(position.y)),
+            ),
+            None => (position, position),
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounds_follow_cells() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+
+        grid.insert(Vec2::new(0, 0), '#');
+        grid.insert(Vec2::new(-3, 2), '#');
+        grid.insert(Vec2::new(1_000_000, -5), '#');
+        assert_eq!(
+            grid.bounds(),
+            Some((Vec2::new(-3, -5), Vec2::new(1_000_000, 2)))
+        );
+        assert_eq!(grid.insert(Vec2::new(0, 0), 'O'), Some('#'));
+        assert_eq!(grid.len(), 3);
+
+        assert_eq!(grid.remove(Vec2::new(1_000_000, -5)), Some('#'));
+        assert_eq!(grid.bounds(), Some((Vec2::new(-3, 0), Vec2::new(0, 2))));
+        assert_eq!(grid.remove(Vec2::new(7, 7)), None);
+    }
+
+    #[test]
+    fn test_densify() {
+        let grid: SparseGrid<char> = [(Vec2::new(-1, -1), '#'), (Vec2::new(1, 0), '#')]
+            .into_iter()
+            .collect();
+        let (dense, origin) = grid.densify('.').unwrap();
+        assert_eq!(origin, Vec2::new(-1, -1));
+        assert_eq!(dense.to_string(), "#..\n..#\n");
+        assert_eq!(SparseGrid::<char>::new().densify('.'), None);
+    }
+
+    #[test]
+    fn test_from_grid() {
+        let dense: Grid<char> = "#.\n.#\n".parse().unwrap();
+        let mut sparse = SparseGrid::from(&dense);
+        assert_eq!(sparse.get(Vec2::new(1, 1)), Some(&'#'));
+
+        sparse.insert(Vec2::new(-1, 0), '#');
+        let (grown, origin) = sparse.densify('.').unwrap();
+        assert_eq!(origin, Vec2::new(-1, 0));
+        assert_eq!(grown.to_string(), "##.\n..#\n");
+    }
+}
@@ -0,0 +1,103 @@
+use std::{
+    fmt::Debug,
+    ops::{Div, Rem},
+};
+
+// The unsigned integers the functions below work with
+pub trait Unsigned: Copy + Eq + Debug + Div<Output = Self> + Rem<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_mul(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl Unsigned for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_unsigned!(u32, u64, u128, usize);
+
+// The greatest common divisor, with gcd(0, n) = n
+pub fn gcd<T: Unsigned>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+// The least common multiple, or None if it doesn't fit in a `T`
+pub fn checked_lcm<T: Unsigned>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    // Dividing first keeps the intermediate value no bigger than the result
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+// Panics if the least common multiple doesn't fit in a `T`
+pub fn lcm<T: Unsigned>(a: T, b: T) -> T {
+    checked_lcm(a, b).unwrap_or_else(|| panic!("LCM of {a:?} and {b:?} overflows"))
+}
+
+// The least common multiple of every value, which is 1 for no values, or None
+// if it doesn't fit in a `T`
+pub fn lcm_iter<T, I>(values: I) -> Option<T>
+where
+    T: Unsigned,
+    I: IntoIterator<Item = T>,
+{
+    values
+        .into_iter()
+        .try_fold(T::ONE, |acc, value| checked_lcm(acc, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(12u64, 18), 6);
+        assert_eq!(gcd(17u64, 5), 1);
+        assert_eq!(gcd(0u64, 7), 7);
+        assert_eq!(gcd(7u64, 0), 7);
+    }
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(lcm(4u64, 6), 12);
+        assert_eq!(lcm(0u64, 6), 0);
+        assert_eq!(checked_lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(
+            checked_lcm(u64::MAX as u128, u64::MAX as u128 - 1),
+            Some(u64::MAX as u128 * (u64::MAX as u128 - 1))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows")]
+    fn test_lcm_overflow_panics() {
+        lcm(u32::MAX, u32::MAX - 1);
+    }
+
+    #[test]
+    fn test_lcm_iter() {
+        // Day 8's example cycle lengths
+        assert_eq!(lcm_iter([2u64, 3]), Some(6));
+        assert_eq!(lcm_iter(1..=20u64), Some(232_792_560));
+        assert_eq!(lcm_iter(Vec::<u64>::new()), Some(1));
+        assert_eq!(lcm_iter(1..=50u64), None);
+        assert!(lcm_iter(1..=50u128).is_some());
+    }
+}
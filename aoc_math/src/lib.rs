@@ -0,0 +1,7 @@
+mod gcd;
+mod linear;
+mod polygon;
+
+pub use gcd::*;
+pub use linear::*;
+pub use polygon::*;
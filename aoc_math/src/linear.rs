@@ -0,0 +1,313 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use crate::gcd;
+
+// An exact fraction, always in lowest terms with a positive denominator so
+// equal values compare and hash the same. Arithmetic panics on i128 overflow
+// rather than silently giving a wrong answer.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Rational {
+    numer: i128,
+    denom: i128,
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational { numer: 0, denom: 1 };
+    pub const ONE: Rational = Rational { numer: 1, denom: 1 };
+
+    // Panics if `denom` is zero
+    pub fn new(numer: i128, denom: i128) -> Rational {
+        assert!(denom != 0, "Denominator should not be zero");
+        let divisor = gcd(numer.unsigned_abs(), denom.unsigned_abs()) as i128;
+        let sign = denom.signum();
+        Rational {
+            numer: sign * numer / divisor,
+            denom: sign * denom / divisor,
+        }
+    }
+
+    pub fn numer(self) -> i128 {
+        self.numer
+    }
+
+    pub fn denom(self) -> i128 {
+        self.denom
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.numer == 0
+    }
+
+    // One over this value. Panics if it is zero.
+    pub fn recip(self) -> Rational {
+        Rational::new(self.denom, self.numer)
+    }
+
+    // The value as a whole number, if it is one
+    pub fn to_integer(self) -> Option<i128> {
+        (self.denom == 1).then_some(self.numer)
+    }
+}
+
+fn checked(value: Option<i128>) -> i128 {
+    value.expect("Rational arithmetic should fit in an i128")
+}
+
+impl From<i128> for Rational {
+    fn from(value: i128) -> Rational {
+        Rational {
+            numer: value,
+            denom: 1,
+        }
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Rational {
+        Rational::from(value as i128)
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, other: Rational) -> Rational {
+        // Scaling by the lcm of the denominators keeps the numbers smaller
+        let divisor = gcd(self.denom.unsigned_abs(), other.denom.unsigned_abs()) as i128;
+        let (left, right) = (other.denom / divisor, self.denom / divisor);
+        let numer = checked(
+            checked(self.numer.checked_mul(left))
+                .checked_add(checked(other.numer.checked_mul(right))),
+        );
+        Rational::new(numer, checked(self.denom.checked_mul(left)))
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Rational {
+            numer: -self.numer,
+            denom: self.denom,
+        }
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, other: Rational) -> Rational {
+        self + -other
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, other: Rational) -> Rational {
+        // Cancelling across first keeps the products small
+        let a = Rational::new(self.numer, other.denom);
+        let b = Rational::new(other.numer, self.denom);
+        Rational::new(
+            checked(a.numer.checked_mul(b.numer)),
+            checked(a.denom.checked_mul(b.denom)),
+        )
+    }
+}
+
+// Panics when dividing by zero
+impl Div for Rational {
+    type Output = Rational;
+
+    fn div(self, other: Rational) -> Rational {
+        self.mul(other.recip())
+    }
+}
+
+// Never overflows, unlike the arithmetic. The denominators are positive, so
+// cross multiplying keeps the order, and values too large for that are
+// compared a whole part at a time instead.
+impl Ord for Rational {
+    fn cmp(&self, other: &Rational) -> Ordering {
+        let by_sign = self.numer.signum().cmp(&other.numer.signum());
+        if by_sign != Ordering::Equal {
+            return by_sign;
+        }
+        match (
+            self.numer.checked_mul(other.denom),
+            other.numer.checked_mul(self.denom),
+        ) {
+            (Some(left), Some(right)) => left.cmp(&right),
+            _ => cmp_fractions((self.numer, self.denom), (other.numer, other.denom)),
+        }
+    }
+}
+
+// Compares a/b with c/d for positive b and d by their continued fractions
+fn cmp_fractions((mut a, mut b): (i128, i128), (mut c, mut d): (i128, i128)) -> Ordering {
+    loop {
+        let whole = a.div_euclid(b).cmp(&c.div_euclid(d));
+        if whole != Ordering::Equal {
+            return whole;
+        }
+        match (a.rem_euclid(b), c.rem_euclid(d)) {
+            (0, 0) => return Ordering::Equal,
+            (0, _) => return Ordering::Less,
+            (_, 0) => return Ordering::Greater,
+            // Flipping both of left/b and right/d over reverses their order,
+            // so it is the same as d/right against b/left
+            (left, right) => (a, b, c, d) = (d, right, b, left),
+        }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Rational) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.to_integer() {
+            Some(value) => write!(f, "{value}"),
+            None => write!(f, "{}/{}", self.numer, self.denom),
+        }
+    }
+}
+
+// Solves `matrix * x = rhs` exactly by Gaussian elimination. `matrix` must be
+// square with one row per value in `rhs`. None if there isn't exactly one
+// solution.
+pub fn solve_linear_system(matrix: &[Vec<Rational>], rhs: &[Rational]) -> Option<Vec<Rational>> {
+    let n = rhs.len();
+    assert!(
+        matrix.len() == n && matrix.iter().all(|row| row.len() == n),
+        "Matrix should be {n} by {n}"
+    );
+    // Each row with its right hand side on the end
+    let mut rows: Vec<Vec<Rational>> = matrix
+        .iter()
+        .zip(rhs)
+        .map(|(row, &value)| row.iter().copied().chain([value]).collect())
+        .collect();
+
+    for column in 0..n {
+        let pivot = (column..n).find(|&row| !rows[row][column].is_zero())?;
+        rows.swap(column, pivot);
+        let pivot = rows[column][column];
+        for value in &mut rows[column][column..] {
+            *value = *value / pivot;
+        }
+        // Clear the column out of every other row
+        let pivot_row = rows[column].clone();
+        for (i, row) in rows.iter_mut().enumerate() {
+            let factor = row[column];
+            if i == column || factor.is_zero() {
+                continue;
+            }
+            for (value, &pivot_value) in row[column..].iter_mut().zip(&pivot_row[column..]) {
+                *value = *value - factor * pivot_value;
+            }
+        }
+    }
+    Some(rows.into_iter().map(|row| row[n]).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matrix(rows: &[&[i128]]) -> Vec<Vec<Rational>> {
+        rows.iter()
+            .map(|row| row.iter().map(|&v| Rational::from(v)).collect())
+            .collect()
+    }
+
+    fn vector(values: &[i128]) -> Vec<Rational> {
+        values.iter().map(|&v| Rational::from(v)).collect()
+    }
+
+    #[test]
+    fn test_rational() {
+        let half = Rational::new(2, 4);
+        assert_eq!(half, Rational::new(-1, -2));
+        assert_eq!((half.numer(), half.denom()), (1, 2));
+        assert_eq!(Rational::new(3, -6), -half);
+        assert_eq!(half + Rational::new(1, 3), Rational::new(5, 6));
+        assert_eq!(half - Rational::ONE, Rational::new(-1, 2));
+        assert_eq!(half * Rational::from(4i128), Rational::from(2i128));
+        assert_eq!(Rational::ONE / Rational::new(-2, 3), Rational::new(-3, 2));
+        assert!(Rational::new(1, 3) < half);
+        assert_eq!(half.to_integer(), None);
+        assert_eq!(Rational::new(6, 3).to_integer(), Some(2));
+        assert_eq!(Rational::new(-7, 2).to_string(), "-7/2");
+    }
+
+    #[test]
+    fn test_cmp_does_not_overflow() {
+        let max = i128::MAX;
+        assert!(Rational::from(max) > Rational::from(-max));
+        assert!(Rational::new(-max, 2) < Rational::new(max, 3));
+        // 1 + 1/(max - 1) against 1 + 1/(max - 2)
+        assert!(Rational::new(max, max - 1) < Rational::new(max - 1, max - 2));
+        assert!(Rational::new(-max, max - 1) > Rational::new(-(max - 1), max - 2));
+        assert_eq!(
+            Rational::new(max, 7).cmp(&Rational::new(max, 7)),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn test_cmp_fractions() {
+        for a in -6..=6 {
+            for b in 1..=6 {
+                for c in -6..=6 {
+                    for d in 1..=6 {
+                        assert_eq!(cmp_fractions((a, b), (c, d)), (a * d).cmp(&(c * b)));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "should not be zero")]
+    fn test_divide_by_zero_panics() {
+        let _ = Rational::ONE / Rational::ZERO;
+    }
+
+    #[test]
+    fn test_solve() {
+        // x + y + z = 6, 2y + 5z = -4, 2x + 5y - z = 27
+        let a = matrix(&[&[1, 1, 1], &[0, 2, 5], &[2, 5, -1]]);
+        let solution = solve_linear_system(&a, &vector(&[6, -4, 27])).unwrap();
+        assert_eq!(solution, vector(&[5, 3, -2]));
+
+        // Needs a row swap, and the answer isn't whole
+        let a = matrix(&[&[0, 2], &[3, 1]]);
+        let solution = solve_linear_system(&a, &vector(&[1, 1])).unwrap();
+        assert_eq!(solution, vec![Rational::new(1, 6), Rational::new(1, 2)]);
+    }
+
+    #[test]
+    fn test_singular() {
+        let a = matrix(&[&[1, 2], &[2, 4]]);
+        assert_eq!(solve_linear_system(&a, &vector(&[3, 6])), None);
+    }
+
+    #[test]
+    fn test_large_values() {
+        // Day 24 sized coordinates
+        let big = 200_000_000_000_000;
+        let a = matrix(&[&[big, 1], &[1, big]]);
+        let rhs = vector(&[big * 3 + 7, 3 + 7 * big]);
+        let solution = solve_linear_system(&a, &rhs).unwrap();
+        assert_eq!(solution, vector(&[3, 7]));
+    }
+}
@@ -0,0 +1,78 @@
+// Helpers for simple polygons whose corners sit on whole-number coordinates,
+// given as the vertices in order around the edge. The last vertex joins back
+// to the first, and repeating the first at the end does no harm. Everything
+// is summed in i128 so long trenches can't overflow.
+use crate::gcd;
+
+// Twice the enclosed area by the shoelace formula, which is always whole
+pub fn twice_area(vertices: &[(i64, i64)]) -> i128 {
+    edges(vertices)
+        .map(|((x1, y1), (x2, y2))| x1 as i128 * y2 as i128 - x2 as i128 * y1 as i128)
+        .sum::<i128>()
+        .abs()
+}
+
+// How many lattice points lie on the edges, vertices included
+pub fn boundary_points(vertices: &[(i64, i64)]) -> i128 {
+    edges(vertices)
+        .map(|((x1, y1), (x2, y2))| gcd(x1.abs_diff(x2), y1.abs_diff(y2)) as i128)
+        .sum()
+}
+
+// How many lattice points lie strictly inside, by Pick's theorem
+pub fn interior_points(vertices: &[(i64, i64)]) -> i128 {
+    (twice_area(vertices) - boundary_points(vertices)) / 2 + 1
+}
+
+// The points inside and on the edge together, e.g. every cube a trench digs out
+pub fn lattice_points(vertices: &[(i64, i64)]) -> i128 {
+    interior_points(vertices) + boundary_points(vertices)
+}
+
+fn edges(vertices: &[(i64, i64)]) -> impl Iterator<Item = ((i64, i64), (i64, i64))> + '_ {
+    vertices
+        .iter()
+        .copied()
+        .zip(vertices.iter().copied().cycle().skip(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_square() {
+        let square = [(0, 0), (4, 0), (4, 4), (0, 4)];
+        assert_eq!(twice_area(&square), 32);
+        assert_eq!(boundary_points(&square), 16);
+        assert_eq!(interior_points(&square), 9);
+        assert_eq!(lattice_points(&square), 25);
+    }
+
+    #[test]
+    fn test_orientation_and_closing_vertex() {
+        let clockwise = [(0, 0), (0, 3), (2, 3), (2, 0)];
+        let closed = [(0, 0), (2, 0), (2, 3), (0, 3), (0, 0)];
+        assert_eq!(twice_area(&clockwise), 12);
+        assert_eq!(twice_area(&closed), 12);
+        assert_eq!(boundary_points(&closed), 10);
+    }
+
+    #[test]
+    fn test_diagonal_edges() {
+        // The long edge passes through (1, 3), (2, 2) and (3, 1)
+        let triangle = [(0, 0), (4, 0), (0, 4)];
+        assert_eq!(twice_area(&triangle), 16);
+        assert_eq!(boundary_points(&triangle), 12);
+        assert_eq!(interior_points(&triangle), 3);
+    }
+
+    #[test]
+    fn test_large_coordinates() {
+        let side = 1 << 40;
+        let square = [(0, 0), (side, 0), (side, side), (0, side)];
+        let side = side as i128;
+        assert_eq!(twice_area(&square), 2 * side * side);
+        assert_eq!(lattice_points(&square), (side + 1) * (side + 1));
+    }
+}
@@ -0,0 +1,78 @@
+use std::str::FromStr;
+
+use anyhow::{Context, Error, Result};
+
+// The groups of lines in `s` that are separated by blank lines, as slices of
+// `s` without their final line ending. Runs of blank lines and blank lines at
+// either end don't make empty blocks. Lines holding only whitespace, like the
+// "\r" left by CRLF endings, count as blank.
+pub fn blocks(s: &str) -> impl Iterator<Item = &str> {
+    let mut rest = s;
+    std::iter::from_fn(move || {
+        // Skip leading blank lines, then take lines up to the next blank one
+        let mut offset = 0;
+        let mut start = None;
+        let mut end = 0;
+        for line in rest.split_inclusive('\n') {
+            if line.trim().is_empty() {
+                if start.is_some() {
+                    break;
+                }
+            } else {
+                start.get_or_insert(offset);
+                end = offset + line.trim_end_matches(['\r', '\n']).len();
+            }
+            offset += line.len();
+        }
+        let block = &rest[start?..end];
+        rest = &rest[offset..];
+        Some(block)
+    })
+}
+
+// Parses each block of `s` as a `T`, saying which block failed
+pub fn parse_blocks<T>(s: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Into<Error>,
+{
+    blocks(s)
+        .enumerate()
+        .map(|(i, block)| {
+            block
+                .parse()
+                .map_err(Into::into)
+                .with_context(|| format!("Invalid block {}", i + 1))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blocks() {
+        let input = "\n\na\nb\n\n\n\nc\n\nd\ne\n\n";
+        assert_eq!(blocks(input).collect::<Vec<_>>(), vec!["a\nb", "c", "d\ne"]);
+        assert_eq!(blocks("").count(), 0);
+        assert_eq!(blocks("\n \n").count(), 0);
+        assert_eq!(blocks("only").collect::<Vec<_>>(), vec!["only"]);
+    }
+
+    #[test]
+    fn test_blocks_crlf() {
+        let input = "a\r\nb\r\n\r\nc\r\n";
+        let blocks: Vec<&str> = blocks(input).collect();
+        assert_eq!(blocks, vec!["a\r\nb", "c"]);
+        assert_eq!(blocks[0].lines().collect::<Vec<_>>(), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_parse_blocks() {
+        assert_eq!(parse_blocks::<u32>("1\n\n23\n").unwrap(), vec![1, 23]);
+
+        let error = parse_blocks::<u32>("1\n\nx\n").unwrap_err();
+        assert_eq!(error.to_string(), "Invalid block 2");
+    }
+}
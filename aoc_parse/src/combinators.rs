@@ -0,0 +1,173 @@
+// nom parsers for the shapes puzzle inputs keep coming in. Run the finished
+// parser with `finish` to get an anyhow error that says where it went wrong.
+use std::str::FromStr;
+
+use anyhow::{anyhow, Result};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{char, digit1, line_ending, multispace0, space0, space1},
+    combinator::{all_consuming, map_res, opt, recognize},
+    error::Error,
+    multi::{many1, separated_list1},
+    sequence::{delimited, pair, preceded, terminated, tuple},
+    IResult, Parser,
+};
+
+pub fn uint<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(digit1, str::parse)(input)
+}
+
+// An integer with an optional leading '-'
+pub fn int<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(recognize(pair(opt(char('-')), digit1)), str::parse)(input)
+}
+
+// Items separated by spaces or tabs, like "79 14  55"
+pub fn spaced<'a, O, P>(item: P) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    P: Parser<&'a str, O, Error<&'a str>>,
+{
+    separated_list1(space1, item)
+}
+
+// Items separated by commas with optional spaces after them, like "1, 2,3"
+pub fn comma_separated<'a, O, P>(item: P) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    P: Parser<&'a str, O, Error<&'a str>>,
+{
+    separated_list1(pair(char(','), space0), item)
+}
+
+// "label: value", with any spaces after the colon
+pub fn labeled<'a, O, P>(label: &'a str, value: P) -> impl FnMut(&'a str) -> IResult<&'a str, O>
+where
+    P: Parser<&'a str, O, Error<&'a str>>,
+{
+    preceded(tuple((tag(label), char(':'), space0)), value)
+}
+
+// "label 12: value", giving the number along with the value
+pub fn numbered<'a, N, O, P>(
+    label: &'a str,
+    value: P,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (N, O)>
+where
+    N: FromStr,
+    P: Parser<&'a str, O, Error<&'a str>>,
+{
+    pair(
+        delimited(pair(tag(label), space1), uint, pair(char(':'), space0)),
+        value,
+    )
+}
+
+// One item per line
+pub fn lines_of<'a, O, P>(item: P) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    P: Parser<&'a str, O, Error<&'a str>>,
+{
+    separated_list1(line_ending, item)
+}
+
+// A row of cells per line, without checking that the rows are the same length
+pub fn grid_of<'a, O, P>(cell: P) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<Vec<O>>>
+where
+    P: Parser<&'a str, O, Error<&'a str>>,
+{
+    separated_list1(line_ending, many1(cell))
+}
+
+// Items separated by a blank line, such as the sections of an almanac
+pub fn paragraphs<'a, O, P>(item: P) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    P: Parser<&'a str, O, Error<&'a str>>,
+{
+    separated_list1(pair(line_ending, line_ending), item)
+}
+
+// Runs `parser` over the whole of `input`, allowing only trailing whitespace
+// after it, and reports the line and column where parsing stopped on failure
+pub fn finish<'a, O, P>(parser: P, input: &'a str) -> Result<O>
+where
+    P: Parser<&'a str, O, Error<&'a str>>,
+{
+    match all_consuming(terminated(parser, multispace0))(input) {
+        Ok((_, output)) => Ok(output),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => {
+            let offset = input.len() - e.input.len();
+            let line = input[..offset].matches('\n').count() + 1;
+            let column = offset - input[..offset].rfind('\n').map_or(0, |i| i + 1) + 1;
+            let found = e.input.lines().next().unwrap_or_default();
+            Err(anyhow!(
+                "Failed to parse line {line}, column {column} ({}): {found:?}",
+                e.code.description()
+            ))
+        }
+        Err(nom::Err::Incomplete(_)) => Err(anyhow!("The input ended too soon")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::character::complete::{alpha1, one_of};
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(uint::<u32>("42 rest"), Ok((" rest", 42)));
+        assert_eq!(int::<i64>("-7,"), Ok((",", -7)));
+        assert!(uint::<u32>("-7").is_err());
+        assert!(uint::<u8>("300").is_err());
+    }
+
+    #[test]
+    fn test_lists() {
+        assert_eq!(
+            finish(
+                labeled("Time", spaced(uint::<u32>)),
+                "Time:      7  15   30\n"
+            )
+            .unwrap(),
+            vec![7, 15, 30]
+        );
+        assert_eq!(
+            finish(comma_separated(int::<i32>), "1, -2,3").unwrap(),
+            vec![1, -2, 3]
+        );
+    }
+
+    #[test]
+    fn test_numbered() {
+        let game = numbered("Game", comma_separated(alpha1));
+        assert_eq!(
+            finish(game, "Game 12: red, blue").unwrap(),
+            (12u32, vec!["red", "blue"])
+        );
+    }
+
+    #[test]
+    fn test_lines_grid_and_paragraphs() {
+        let input = "#.\n.#\n\n..\n##\n";
+        let grids = finish(paragraphs(grid_of(one_of("#."))), input).unwrap();
+        assert_eq!(
+            grids,
+            vec![
+                vec![vec!['#', '.'], vec!['.', '#']],
+                vec![vec!['.', '.'], vec!['#', '#']],
+            ]
+        );
+        assert_eq!(
+            finish(lines_of(uint::<u32>), "1\n2\r\n3").unwrap(),
+            vec![1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn test_finish_reports_position() {
+        let error = finish(lines_of(spaced(uint::<u32>)), "1 2\n3 x 4\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Failed to parse line 2, column 3 (End of file): \"x 4\""
+        );
+    }
+}
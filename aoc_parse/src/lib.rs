@@ -0,0 +1,6 @@
+mod blocks;
+pub mod combinators;
+mod numbers;
+
+pub use blocks::*;
+pub use numbers::*;
@@ -0,0 +1,124 @@
+use std::str::FromStr;
+
+use anyhow::{ensure, Context, Error, Result};
+
+// Every integer in `s`, ignoring whatever text is around them. A '-' directly
+// before the digits makes the number negative. Panics on numbers too big for
+// an i64.
+pub fn ints(s: &str) -> Vec<i64> {
+    scan(s, true)
+}
+
+// Every run of digits in `s`, ignoring whatever text is around them, so
+// "1-3" gives 1 and 3. Panics on numbers too big for a u64.
+pub fn uints(s: &str) -> Vec<u64> {
+    scan(s, false)
+}
+
+// The integers in `s` taken two at a time, e.g. the x and y of "x=3, y=-4"
+pub fn signed_pairs(s: &str) -> Result<Vec<(i64, i64)>> {
+    let numbers = ints(s);
+    ensure!(
+        numbers.len().is_multiple_of(2),
+        "{} numbers in {s:?} do not make whole pairs",
+        numbers.len()
+    );
+    Ok(numbers.chunks(2).map(|pair| (pair[0], pair[1])).collect())
+}
+
+// The non-empty pieces of `s` between whitespace and commas
+pub fn fields(s: &str) -> impl Iterator<Item = &str> {
+    s.split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|field| !field.is_empty())
+}
+
+// Parses every field of `s`, failing on the first one that isn't a `T` rather
+// than skipping it like `ints` would
+pub fn parse_fields<T>(s: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Into<Error>,
+{
+    fields(s)
+        .map(|field| {
+            field
+                .parse()
+                .map_err(Into::into)
+                .with_context(|| format!("Invalid number {field:?}"))
+        })
+        .collect()
+}
+
+fn scan<T>(s: &str, signed: bool) -> Vec<T>
+where
+    T: FromStr,
+    T::Err: std::fmt::Debug,
+{
+    let bytes = s.as_bytes();
+    let mut numbers = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        let negative = signed && i > 0 && bytes[i - 1] == b'-';
+        let start = if negative { i - 1 } else { i };
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        let number = s[start..i]
+            .parse()
+            .unwrap_or_else(|e| panic!("{:?} should fit: {e:?}", &s[start..i]));
+        numbers.push(number);
+    }
+    numbers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(ints("Card  12: 41 -48 | 83"), vec![12, 41, -48, 83]);
+        assert_eq!(ints("x=3, y=-4"), vec![3, -4]);
+        assert_eq!(ints("1-3"), vec![1, -3]);
+        assert_eq!(ints("- 5 --6"), vec![5, -6]);
+        assert!(ints("no numbers").is_empty());
+    }
+
+    #[test]
+    fn test_uints() {
+        assert_eq!(uints("Card  12: 41 -48 | 83"), vec![12, 41, 48, 83]);
+        assert_eq!(uints("1-3"), vec![1, 3]);
+        assert_eq!(uints("18446744073709551615"), vec![u64::MAX]);
+    }
+
+    #[test]
+    #[should_panic(expected = "should fit")]
+    fn test_ints_overflow_panics() {
+        ints("99999999999999999999");
+    }
+
+    #[test]
+    fn test_signed_pairs() {
+        assert_eq!(signed_pairs("p=0,4 v=3,-3").unwrap(), vec![(0, 4), (3, -3)]);
+        assert!(signed_pairs("1 2 3").is_err());
+    }
+
+    #[test]
+    fn test_fields() {
+        let fields: Vec<&str> = fields(" 1, 2,,3\t4 \n").collect();
+        assert_eq!(fields, vec!["1", "2", "3", "4"]);
+    }
+
+    #[test]
+    fn test_parse_fields() {
+        assert_eq!(parse_fields::<i32>("0 -3, 6").unwrap(), vec![0, -3, 6]);
+        assert!(parse_fields::<i32>("").unwrap().is_empty());
+
+        let error = parse_fields::<u32>("3 f 2").unwrap_err();
+        assert_eq!(error.to_string(), "Invalid number \"f\"");
+    }
+}
@@ -0,0 +1,219 @@
+use std::{cmp, str::FromStr};
+
+use anyhow::{bail, Result};
+use aoc_parse::combinators::{comma_separated, finish, numbered, uint};
+use nom::{
+    character::complete::{alpha1, space1},
+    combinator::rest,
+    sequence::separated_pair,
+    IResult,
+};
+
+#[derive(Debug, PartialEq)]
+struct Game {
+    id: u32,
+    max_red: u32,
+    max_green: u32,
+    max_blue: u32,
+}
+
+pub fn sum_of_possible_games(input: &str) -> u32 {
+    input
+        .lines()
+        .map(parse_game)
+        .filter(game_is_possible)
+        .fold(0, |mut acc, game| {
+            acc += game.id;
+            acc
+        })
+}
+
+aoc_core::register_solution!(2, 1, sum_of_possible_games);
+
+fn parse_game(input: &str) -> Game {
+    let (id, tail): (u32, &str) =
+        finish(numbered("Game", rest), input.trim()).expect("Invalid Game Format");
+    let (max_red, max_green, max_blue) =
+        tail.split(';')
+            .map(parse_colors)
+            .fold((0, 0, 0), |mut acc, (r, g, b)| {
+                acc.0 = cmp::max(acc.0, r);
+                acc.1 = cmp::max(acc.1, g);
+                acc.2 = cmp::max(acc.2, b);
+                acc
+            });
+    Game {
+        id,
+        max_red,
+        max_green,
+        max_blue,
+    }
+}
+
+fn parse_colors(game: &str) -> (u32, u32, u32) {
+    let round = game.parse::<Round>().expect("Failed to parse round");
+    (round.red, round.green, round.blue)
+}
+
+#[derive(Debug, PartialEq, Default)]
+struct Round {
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+impl FromStr for Round {
+    type Err = anyhow::Error;
+
+    // Colors can come in any order and any missing color counts as zero
+    fn from_str(s: &str) -> Result<Self> {
+        let mut round = Round::default();
+        for (value, color) in finish(cube_counts, s.trim())? {
+            match color {
+                "red" => round.red += value,
+                "green" => round.green += value,
+                "blue" => round.blue += value,
+                _ => bail!("Unknown color {color:?}"),
+            }
+        }
+        Ok(round)
+    }
+}
+
+// "3 blue, 4 red" as (count, color) pairs
+fn cube_counts(input: &str) -> IResult<&str, Vec<(u32, &str)>> {
+    comma_separated(separated_pair(uint, space1, alpha1))(input)
+}
+
+fn game_is_possible(game: &Game) -> bool {
+    if game.max_red > 12 {
+        return false;
+    }
+    if game.max_green > 13 {
+        return false;
+    }
+    if game.max_blue > 14 {
+        return false;
+    }
+    true
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_game_is_possible_returns_true_for_valid_game() {
+        let game = Game {
+            id: 0,
+            max_red: 4,
+            max_green: 2,
+            max_blue: 6,
+        };
+
+        assert!(game_is_possible(&game));
+    }
+
+    #[test]
+    fn test_game_is_possible_returns_false_for_invalid_game() {
+        let game = Game {
+            id: 0,
+            max_red: 20,
+            max_green: 13,
+            max_blue: 6,
+        };
+
+        assert!(!game_is_possible(&game));
+    }
+
+    #[test]
+    fn test_parse_game_returns_correct_values() {
+        let test_data = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+        let expected = Game {
+            id: 1,
+            max_red: 4,
+            max_green: 2,
+            max_blue: 6,
+        };
+        let actual = parse_game(test_data);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_parse_colors_returns_correct_values_all_values() {
+        let test_data = "1 red, 2 green, 6 blue";
+        let expected = (1, 2, 6);
+        let actual = parse_colors(test_data);
+
+        assert_eq!(expected.0, actual.0);
+        assert_eq!(expected.1, actual.1);
+        assert_eq!(expected.2, actual.2);
+    }
+
+    #[test]
+    fn test_parse_colors_returns_correct_values_partial_values() {
+        let test_data = "3 blue, 4 red";
+        let expected = (4, 0, 3);
+        let actual = parse_colors(test_data);
+
+        assert_eq!(expected.0, actual.0);
+        assert_eq!(expected.1, actual.1);
+        assert_eq!(expected.2, actual.2);
+    }
+
+    #[test]
+    fn test_round_from_str_any_color_order() {
+        let expected = Round {
+            red: 1,
+            green: 2,
+            blue: 6,
+        };
+        let permutations = [
+            "1 red, 2 green, 6 blue",
+            "1 red, 6 blue, 2 green",
+            "2 green, 1 red, 6 blue",
+            "2 green, 6 blue, 1 red",
+            "6 blue, 1 red, 2 green",
+            "6 blue, 2 green, 1 red",
+        ];
+
+        for round in permutations {
+            assert_eq!(round.parse::<Round>().unwrap(), expected, "{round}");
+        }
+    }
+
+    #[test]
+    fn test_round_from_str_single_color() {
+        let expected = Round {
+            red: 0,
+            green: 2,
+            blue: 0,
+        };
+        let actual = " 2 green".parse::<Round>().unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_round_from_str_returns_error_for_unknown_color() {
+        let actual = "3 blue, 4 purple".parse::<Round>();
+
+        let error = actual.expect_err("purple is not a valid color");
+        assert_eq!(error.to_string(), "Unknown color \"purple\"");
+    }
+
+    #[test]
+    fn test_data_returns_correct_sum() {
+        let test_data = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+        Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+        Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+        Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+        Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+        let expected = 8;
+        let actual = sum_of_possible_games(test_data);
+
+        assert_eq!(expected, actual);
+    }
+}
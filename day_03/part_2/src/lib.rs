@@ -0,0 +1,405 @@
+use std::collections::{HashMap, HashSet};
+
+pub fn solve_part(input: &str) -> u32 {
+    let mut schematic = Schematic::from(input);
+    schematic.calculate_parts();
+    schematic.calculate_ratios()
+}
+
+aoc_core::register_solution!(3, 2, solve_part);
+
+trait EngineParts {
+    fn is_engine_part_symbol(&self) -> bool;
+    fn is_engine_gear_symbol(&self) -> bool;
+}
+
+impl EngineParts for char {
+    #[inline]
+    fn is_engine_part_symbol(&self) -> bool {
+        // Everything but '.' and the '*' gear, which is tracked separately
+        matches!(*self, '!'..=')' | '+'..='-' | '/' | ':'..='@' | '['..='`' | '{'..='~')
+    }
+    #[inline]
+    fn is_engine_gear_symbol(&self) -> bool {
+        matches!(*self, '*')
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+struct Position(usize, usize);
+
+impl Position {
+    fn get_adjacent_positions(&self) -> Vec<Position> {
+        let mut output = Vec::<Position>::new();
+        let Position(x, y) = *self;
+
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                if dx == 0 && dy == 0 {
+                    continue;
+                }
+                let nx = x as i32 + dx;
+                let ny = y as i32 + dy;
+                if nx >= 0 && ny >= 0 {
+                    output.push(Position(nx as usize, ny as usize));
+                }
+            }
+        }
+
+        output
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum SchematicElement {
+    Number(usize),
+    Symbol,
+    Gear,
+}
+
+#[derive(Debug, PartialEq)]
+struct Number {
+    value: u32,
+    position: Position,
+    is_part: bool,
+}
+
+impl Number {
+    fn new(value: u32, position: Position) -> Number {
+        Number {
+            value,
+            position,
+            is_part: false,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.value.to_string().len()
+    }
+
+    fn span(&self) -> NumberSpan {
+        let Position(x, y) = self.position;
+        NumberSpan {
+            value: self.value,
+            row: y,
+            col_start: x,
+            col_end: x + self.len() - 1,
+        }
+    }
+}
+
+// The full range of cells a number occupies, so adjacency is checked once per
+// number rather than once per digit.
+#[derive(Debug, PartialEq)]
+struct NumberSpan {
+    value: u32,
+    row: usize,
+    col_start: usize,
+    col_end: usize,
+}
+
+fn is_adjacent_to_symbol(span: &NumberSpan, symbol: Position) -> bool {
+    let Position(x, y) = symbol;
+    y + 1 >= span.row && y <= span.row + 1 && x + 1 >= span.col_start && x <= span.col_end + 1
+}
+
+struct Schematic {
+    numbers: Vec<Number>,
+    map: HashMap<Position, SchematicElement>,
+}
+
+fn get_numbers(s: &str, cols: usize) -> Vec<Number> {
+    let mut numbers = Vec::new();
+    let mut current_number = String::new();
+    let mut start_index = None;
+
+    for (i, c) in s.char_indices() {
+        if c.is_numeric() {
+            current_number.push(c);
+            if start_index.is_none() {
+                start_index = Some(i);
+            }
+        } else if !current_number.is_empty() {
+            let n = Number::new(
+                current_number
+                    .parse::<u32>()
+                    .expect("Failed to Parse Number"),
+                get_position(cols, start_index.unwrap()),
+            );
+            numbers.push(n);
+            current_number.clear();
+            start_index = None;
+        }
+    }
+
+    if !current_number.is_empty() {
+        let n = Number::new(
+            current_number
+                .parse::<u32>()
+                .expect("Failed to Parse Number"),
+            get_position(cols, start_index.unwrap()),
+        );
+        numbers.push(n);
+    }
+
+    numbers
+}
+
+fn get_part_symbols(s: &str, cols: usize) -> Vec<Position> {
+    let mut output = Vec::<Position>::new();
+    for (i, _) in s.char_indices().filter(|&(_, c)| c.is_engine_part_symbol()) {
+        let position = get_position(cols, i);
+        output.push(position);
+    }
+
+    output
+}
+
+fn get_gear_symbols(s: &str, cols: usize) -> Vec<Position> {
+    let mut output = Vec::<Position>::new();
+    for (i, _) in s.char_indices().filter(|&(_, c)| c.is_engine_gear_symbol()) {
+        let position = get_position(cols, i);
+        output.push(position);
+    }
+
+    output
+}
+
+impl Schematic {
+    fn calculate_parts(&mut self) {
+        let mut to_update = HashSet::new();
+
+        for s in self
+            .map
+            .iter()
+            .filter(|(_, e)| matches!(e, SchematicElement::Symbol | SchematicElement::Gear))
+        {
+            let position = *s.0;
+            let get_adjacent_positions = &position.get_adjacent_positions();
+            let adjacent_numbers =
+                get_adjacent_positions
+                    .iter()
+                    .filter_map(|p| match self.map.get(p)? {
+                        SchematicElement::Number(index) => Some(*index),
+                        _ => None,
+                    });
+
+            for number in adjacent_numbers {
+                to_update.insert(number);
+            }
+        }
+
+        for number in to_update {
+            self.numbers[number].is_part = true;
+        }
+    }
+
+    fn calculate_ratios(&mut self) -> u32 {
+        let mut total = 0;
+
+        for s in self
+            .map
+            .iter()
+            .filter(|(_, e)| matches!(e, SchematicElement::Gear))
+        {
+            let position = *s.0;
+            let adjacent_spans: Vec<_> = self
+                .numbers
+                .iter()
+                .filter(|number| number.is_part)
+                .map(Number::span)
+                .filter(|span| is_adjacent_to_symbol(span, position))
+                .collect();
+
+            if adjacent_spans.len() == 2 {
+                let sub_total = adjacent_spans
+                    .iter()
+                    .map(|span| span.value)
+                    .product::<u32>();
+                total += sub_total;
+            }
+        }
+
+        total
+    }
+}
+
+impl From<&str> for Schematic {
+    fn from(s: &str) -> Self {
+        let columns = s.lines().next().unwrap().len();
+        let s: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+
+        let numbers = get_numbers(&s, columns);
+        let part_symbols = get_part_symbols(&s, columns);
+        let gear_symbols = get_gear_symbols(&s, columns);
+        let mut map = HashMap::<Position, SchematicElement>::new();
+        for (i, number) in numbers.iter().enumerate() {
+            let x = number.position.0;
+            for pos in x..x + number.len() {
+                let position = Position(pos, number.position.1);
+                map.insert(position, SchematicElement::Number(i));
+            }
+        }
+        for part_symbol in part_symbols.iter() {
+            map.insert(*part_symbol, SchematicElement::Symbol);
+        }
+        for gear_symbol in gear_symbols.iter() {
+            map.insert(*gear_symbol, SchematicElement::Gear);
+        }
+
+        Schematic { numbers, map }
+    }
+}
+
+fn get_position(columns: usize, index: usize) -> Position {
+    let x = index % columns;
+    let y = index / columns;
+    Position(x, y)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_get_position_returns_correct_position_simple() {
+        let cols = 10;
+        let index = 1;
+
+        let expected = Position(1, 0);
+        let actual = get_position(cols, index);
+
+        assert_eq!(expected, actual);
+    }
+    #[test]
+    fn test_get_position_returns_correct_position_complex() {
+        let cols = 10;
+        let index = 23;
+
+        let expected = Position(3, 2);
+        let actual = get_position(cols, index);
+
+        assert_eq!(expected, actual);
+    }
+    #[test]
+    fn test_get_numbers_returns_expected_result() {
+        let test_data = "467..114";
+
+        let expected = [
+            Number::new(467, Position(0, 0)),
+            Number::new(114, Position(1, 1)),
+        ];
+        let expected_len = 2;
+
+        let actual = get_numbers(test_data, 4);
+        let actual_len = actual.len();
+
+        assert_eq!(expected_len, actual_len);
+        assert_eq!(expected[0], actual[0]);
+        assert_eq!(expected[1], actual[1]);
+    }
+
+    #[test]
+    fn test_number_span_covers_all_digits() {
+        let expected = NumberSpan {
+            value: 467,
+            row: 2,
+            col_start: 3,
+            col_end: 5,
+        };
+
+        let actual = Number::new(467, Position(3, 2)).span();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_is_adjacent_to_symbol() {
+        let span = Number::new(467, Position(1, 1)).span();
+
+        // Diagonal to the first and last digits
+        assert!(is_adjacent_to_symbol(&span, Position(0, 0)));
+        assert!(is_adjacent_to_symbol(&span, Position(4, 2)));
+        // Directly above the middle digit
+        assert!(is_adjacent_to_symbol(&span, Position(2, 0)));
+        // One column too far on either side
+        assert!(!is_adjacent_to_symbol(&span, Position(5, 1)));
+        // Two rows below
+        assert!(!is_adjacent_to_symbol(&span, Position(2, 3)));
+    }
+
+    #[test]
+    fn test_calculate_ratios_counts_multi_digit_number_once() {
+        // The gear touches every digit of 123, which must still only count once
+        let test_data = "123.\n.*..\n45#.";
+        let mut schematic = Schematic::from(test_data);
+        schematic.calculate_parts();
+
+        assert_eq!(123 * 45, schematic.calculate_ratios());
+    }
+
+    #[test]
+    fn test_provided_test_data_returns_expected_ratio() {
+        let test_data = "467..114..\n...*......\n..35..633.\n......#...\n617*......\n.....+.58.\n..592.....\n......755.\n...$.*....\n.664.598..";
+        let mut schematic = Schematic::from(test_data);
+        schematic.calculate_parts();
+
+        assert_eq!(467835, schematic.calculate_ratios());
+    }
+
+    #[test]
+    fn test_get_part_symbols_returns_expected_result() {
+        let test_data = "467./114";
+
+        let expected = [Position(0, 1)];
+        let expected_len = 1;
+
+        let actual = get_part_symbols(test_data, 4);
+        let actual_len = actual.len();
+
+        assert_eq!(expected_len, actual_len);
+        assert_eq!(expected[0], actual[0]);
+    }
+
+    #[test]
+    fn test_schematic_from_returns_expected_result() {
+        let test_data = "467.\n/114";
+
+        let expected_numbers = [
+            Number::new(467, Position(0, 0)),
+            Number::new(114, Position(1, 1)),
+        ];
+        let expected_numbers_len = 2;
+        let expected_map = [
+            (Position(0, 0), SchematicElement::Number(0)),
+            (Position(1, 1), SchematicElement::Number(1)),
+            (Position(0, 1), SchematicElement::Symbol),
+        ];
+        let expected_map_len = 7;
+
+        let actual = Schematic::from(test_data);
+        let actual_numbers_len = actual.numbers.len();
+        let actual_map_len = actual.map.len();
+
+        assert_eq!(expected_numbers_len, actual_numbers_len);
+        assert_eq!(expected_map_len, actual_map_len);
+        assert_eq!(expected_numbers[0], actual.numbers[0]);
+        assert_eq!(expected_numbers[1], actual.numbers[1]);
+        assert!(actual.map.contains_key(&expected_map[0].0));
+        assert_eq!(Some(&expected_map[0].1), actual.map.get(&expected_map[0].0));
+        assert!(actual.map.contains_key(&expected_map[1].0));
+        assert_eq!(Some(&expected_map[1].1), actual.map.get(&expected_map[1].0));
+        assert!(actual.map.contains_key(&expected_map[2].0));
+        assert_eq!(Some(&expected_map[2].1), actual.map.get(&expected_map[2].0));
+    }
+
+    // #[test]
+    // fn test_provided_test_data_returns_expected_result() {
+    //     let test_data = "467..114..\n...*......\n..35..633.\n......#...\n617*......\n.....+.58.\n..592.....\n......755.\n...$.*....\n.664.598..";
+    //     let expected_sum = 4361;
+    //     let mut schematic = Schematic::from(test_data);
+    //     schematic.calculate_parts();
+    //     let actual_sum = schematic.get_part_sum();
+
+    //     assert_eq!(expected_sum, actual_sum);
+    // }
+}
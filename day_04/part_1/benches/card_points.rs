@@ -0,0 +1,39 @@
+// Compares summing card points sequentially against doing it with rayon.
+//
+// Each card is only a small set intersection, so at puzzle sized inputs most
+// of what the thread pool could save is eaten by handing the work out. `cargo
+// bench -p day_04_part_1` reports the time of both variants for comparison.
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_04_part_1::{parse_cards, solve_part_1, solve_part_1_parallel};
+
+// Puzzle shaped cards: 10 winning numbers and 25 played numbers from 1..=99
+fn cards_input(count: usize) -> String {
+    (1..=count)
+        .map(|id| {
+            let winners: Vec<_> = (0..10)
+                .map(|i| ((id * 7 + i * 13) % 99 + 1).to_string())
+                .collect();
+            let plays: Vec<_> = (0..25)
+                .map(|i| ((id * 11 + i * 17) % 99 + 1).to_string())
+                .collect();
+            format!("Card {id}: {} | {}", winners.join(" "), plays.join(" "))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn bench_card_points(c: &mut Criterion) {
+    let cards = parse_cards(&cards_input(250));
+
+    let mut group = c.benchmark_group("card_points_250");
+    group.bench_function("sequential", |b| {
+        b.iter(|| black_box(solve_part_1(black_box(&cards))))
+    });
+    group.bench_function("parallel", |b| {
+        b.iter(|| black_box(solve_part_1_parallel(black_box(&cards))))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_card_points);
+criterion_main!(benches);
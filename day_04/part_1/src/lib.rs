@@ -0,0 +1,135 @@
+use std::collections::HashSet;
+
+use rayon::prelude::*;
+
+#[derive(Debug, PartialEq)]
+pub struct Scratchcard {
+    pub id: u32,
+    winners: HashSet<u32>,
+    plays: HashSet<u32>,
+}
+
+impl Scratchcard {
+    pub fn parse(line: &str) -> Scratchcard {
+        let (head, tail) = line
+            .split_once(':')
+            .expect("Game should be in format Game x : xxx");
+        let (winners, plays) = tail
+            .split_once('|')
+            .expect("Game should be in format: x x | x x");
+        Scratchcard {
+            id: parse_card_number(head),
+            winners: parse_numbers_list(winners),
+            plays: parse_numbers_list(plays),
+        }
+    }
+
+    pub fn points(&self) -> u64 {
+        let matches_count = self.winners.intersection(&self.plays).count() as u32;
+        calculate_score(matches_count) as u64
+    }
+}
+
+pub fn parse_cards(input: &str) -> Vec<Scratchcard> {
+    input.lines().map(Scratchcard::parse).collect()
+}
+
+pub fn solve_part_1(cards: &[Scratchcard]) -> u64 {
+    cards.iter().map(|c| c.points()).sum()
+}
+
+// Kept for the benchmark to compare against solve_part_1
+pub fn solve_part_1_parallel(cards: &[Scratchcard]) -> u64 {
+    cards.par_iter().map(|c| c.points()).sum()
+}
+
+aoc_core::register_solution!(4, 1, |input| solve_part_1(&parse_cards(input)));
+
+fn parse_card_number(input: &str) -> u32 {
+    let number = *aoc_parse::uints(input)
+        .first()
+        .expect("Game head should have a card number");
+    number.try_into().expect("Failed to parse card as u32")
+}
+
+fn parse_numbers_list(input: &str) -> HashSet<u32> {
+    aoc_parse::parse_fields(input)
+        .expect("Input numbers should be in a valid number format")
+        .into_iter()
+        .collect()
+}
+
+fn calculate_score(wins: u32) -> u32 {
+    if wins <= 2 {
+        return wins;
+    }
+
+    2u32.pow(wins - 1)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_parse_card_number_returns_correctly_single_digit() {
+        let test_data = "Card 3";
+        let expected = 3;
+        let actual = parse_card_number(test_data);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_parse_card_number_returns_correctly_multi_digit() {
+        let test_data = "Card 111";
+        let expected = 111;
+        let actual = parse_card_number(test_data);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_calculate_score_returns_true_for_1_win() {
+        let expected = 1;
+        let actual = calculate_score(1);
+
+        assert_eq!(expected, actual);
+    }
+    #[test]
+    fn test_calculate_score_returns_true_for_2_win() {
+        let expected = 2;
+        let actual = calculate_score(2);
+
+        assert_eq!(expected, actual);
+    }
+    #[test]
+    fn test_calculate_score_returns_true_for_3_win() {
+        let expected = 4;
+        let actual = calculate_score(3);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_scratchcard_points_returns_correct_score() {
+        let test_data = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53";
+        let expected = 8;
+        let actual = Scratchcard::parse(test_data).points();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_solve_part_1_parallel_matches_sequential() {
+        let test_data = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+        let cards = parse_cards(test_data);
+
+        assert_eq!(solve_part_1(&cards), 13);
+        assert_eq!(solve_part_1_parallel(&cards), 13);
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::{HashMap, HashSet};
+
+pub fn solve_part(input: &str) -> u32 {
+    let cards: HashMap<usize, u32> = input
+        .lines()
+        .map(|l| {
+            l.split_once(':')
+                .expect("Game should be in format Game x : xxx")
+        })
+        .map(|(head, tail)| (parse_card_number(head), parse_game_score(tail)))
+        .collect();
+
+    let mut card_frequency: HashMap<usize, u32> = cards.keys().map(|k| (*k, 1)).collect();
+
+    for i in 1..=card_frequency.len() {
+        let frequency = *card_frequency
+            .get(&i)
+            .expect("Card should be in card_frequency") as usize;
+        let score = *cards.get(&i).expect("Card should be in cards") as usize;
+        for _ in 0..frequency {
+            for k in i + 1..=i + score {
+                let count = card_frequency.entry(k).or_insert(0);
+                *count += 1;
+            }
+        }
+    }
+
+    card_frequency.values().sum()
+}
+
+aoc_core::register_solution!(4, 2, solve_part);
+
+fn parse_card_number(input: &str) -> usize {
+    let number = *aoc_parse::uints(input)
+        .first()
+        .expect("Game head should have a card number");
+    number.try_into().expect("Failed to parse card as usize")
+}
+
+fn parse_numbers_list(input: &str) -> HashSet<u32> {
+    aoc_parse::parse_fields(input)
+        .expect("Input numbers should be in a valid number format")
+        .into_iter()
+        .collect()
+}
+
+fn parse_game_score(input: &str) -> u32 {
+    let (winners, plays) = input
+        .split_once('|')
+        .expect("Game should be in format: x x | x x");
+    let (winners, plays) = (parse_numbers_list(winners), parse_numbers_list(plays));
+    winners.intersection(&plays).count() as u32
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_parse_card_number_returns_correctly_single_digit() {
+        let test_data = "Card 3";
+        let expected = 3;
+        let actual = parse_card_number(test_data);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_parse_card_number_returns_correctly_multi_digit() {
+        let test_data = "Card 111";
+        let expected = 111;
+        let actual = parse_card_number(test_data);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_parse_game_score_returns_correct_score() {
+        // Part 2 only needs the number of matches, not the doubled score
+        let test_data = "41 48 83 86 17 | 83 86  6 31 17  9 48 53";
+        let expected = 4;
+        let actual = parse_game_score(test_data);
+
+        assert_eq!(expected, actual);
+    }
+}
@@ -1,6 +1,7 @@
-use rayon::prelude::*;
 use std::error::Error;
 
+use aoc_algo::RangeSet;
+
 use crate::converter::Converter;
 use crate::parser::parse_almanac;
 
@@ -28,26 +29,36 @@ impl Almanac {
             .collect()
     }
 
-    pub fn calculate_seed_locations_with_seed_ranges(&self) -> Vec<usize> {
-        assert!(self.seeds.len() % 2 == 0, "Seeds must be in pairs");
-        self.seeds
-            .par_chunks(2)
-            .flat_map(|pair| {
-                (pair[0]..pair[0] + pair[1])
-                    .into_par_iter()
-                    .map(move |seed| self.convert_seed_to_location(seed))
-            })
-            .collect()
+    // Maps each whole seed range through the almanac rather than every seed
+    pub fn calculate_seed_locations_with_seed_ranges(&self) -> RangeSet {
+        assert!(self.seeds.len().is_multiple_of(2), "Seeds must be in pairs");
+        let seeds = self
+            .seeds
+            .chunks(2)
+            .map(|pair| pair[0] as u64..(pair[0] + pair[1]) as u64)
+            .collect();
+        self.converters()
+            .into_iter()
+            .fold(seeds, |values, converter| converter.convert_ranges(&values))
     }
 
     fn convert_seed_to_location(&self, seed: usize) -> usize {
-        let mut res = self.seed_to_soil_map.convert(seed);
-        res = self.soil_to_fertilizer_map.convert(res);
-        res = self.fertilizer_to_water_map.convert(res);
-        res = self.water_to_light_map.convert(res);
-        res = self.light_to_temperature_map.convert(res);
-        res = self.temperature_to_humidity_map.convert(res);
-        self.humidity_to_location_map.convert(res)
+        self.converters()
+            .into_iter()
+            .fold(seed, |value, converter| converter.convert(value))
+    }
+
+    // In the order a seed passes through them
+    fn converters(&self) -> [&Converter; 7] {
+        [
+            &self.seed_to_soil_map,
+            &self.soil_to_fertilizer_map,
+            &self.fertilizer_to_water_map,
+            &self.water_to_light_map,
+            &self.light_to_temperature_map,
+            &self.temperature_to_humidity_map,
+            &self.humidity_to_location_map,
+        ]
     }
 }
 
@@ -96,4 +107,11 @@ mod test {
             vec![82, 43, 86, 35]
         );
     }
+
+    #[test]
+    fn test_calculate_seed_locations_with_seed_ranges() {
+        let locations = TEST_ALMANAC.calculate_seed_locations_with_seed_ranges();
+        assert_eq!(locations.len(), 14 + 13);
+        assert_eq!(locations.min(), Some(46));
+    }
 }
@@ -1,3 +1,5 @@
+use aoc_algo::{RangeMap, RangeSet};
+
 use crate::map::Map;
 
 #[derive(Debug, PartialEq)]
@@ -16,6 +18,19 @@ impl Converter {
         }
         value
     }
+
+    // The same conversion for whole ranges of values at once
+    pub fn convert_ranges(&self, values: &RangeSet) -> RangeSet {
+        let mut range_map = RangeMap::new();
+        for map in &self.0 {
+            let source = map.source_range();
+            range_map.insert(
+                source.start as u64..source.end as u64,
+                map.destination_range_start() as u64,
+            );
+        }
+        range_map.map_set(values)
+    }
 }
 
 #[cfg(test)]
@@ -49,6 +64,20 @@ mod test {
         assert_eq!(TEST_CONVERTER.convert(60), 56);
     }
 
+    #[test]
+    fn test_convert_ranges_matches_convert() {
+        let values: RangeSet = [0..20, 50..110].into_iter().collect();
+        let expected: RangeSet = values
+            .ranges()
+            .iter()
+            .flat_map(|range| range.clone())
+            .map(|value| TEST_CONVERTER.convert(value as usize) as u64)
+            .map(|value| value..value + 1)
+            .collect();
+
+        assert_eq!(TEST_CONVERTER.convert_ranges(&values), expected);
+    }
+
     #[test]
     fn test_convert_out_of_range() {
         assert_eq!(TEST_CONVERTER.convert(97), 97);
@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 #[derive(Debug, PartialEq)]
 pub struct Map {
     destination_range_start: usize,
@@ -18,8 +20,16 @@ impl Map {
         }
     }
 
+    pub fn source_range(&self) -> Range<usize> {
+        self.source_range_start..self.source_range_start + self.range_length
+    }
+
+    pub fn destination_range_start(&self) -> usize {
+        self.destination_range_start
+    }
+
     fn is_in_range(&self, value: usize) -> bool {
-        (self.source_range_start..self.source_range_start + self.range_length).contains(&value)
+        self.source_range().contains(&value)
     }
 
     pub fn convert(&self, value: usize) -> Option<usize> {
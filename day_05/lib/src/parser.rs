@@ -1,11 +1,10 @@
 use std::error::Error;
 
+use aoc_parse::combinators::{labeled, spaced, uint};
 use nom::{
-    bytes::complete::tag,
-    character::complete::{digit1, line_ending, space1},
-    combinator::{map, map_res, opt},
-    multi::many1,
-    sequence::{preceded, separated_pair, terminated, tuple},
+    character::complete::space1,
+    combinator::{all_consuming, map},
+    sequence::{preceded, tuple},
     IResult,
 };
 
@@ -14,11 +13,11 @@ use crate::converter::Converter;
 use crate::map::Map;
 
 fn parse_integer(input: &str) -> IResult<&str, usize> {
-    map_res(digit1, str::parse)(input)
+    uint(input)
 }
 
 fn parse_seeds(input: &str) -> IResult<&str, Vec<usize>> {
-    preceded(tag("seeds:"), many1(preceded(space1, parse_integer)))(input)
+    labeled("seeds", spaced(parse_integer))(input)
 }
 
 fn parse_map(input: &str) -> IResult<&str, Map> {
@@ -35,57 +34,49 @@ fn parse_map(input: &str) -> IResult<&str, Map> {
     )(input)
 }
 
-fn parse_converter<'a>(input: &'a str, section_name: &str) -> IResult<&'a str, Converter> {
-    let section_header = preceded(
-        opt(line_ending),
-        separated_pair(tag(section_name), tag(":"), line_ending),
-    );
-    let parser = preceded(
-        section_header,
-        many1(terminated(parse_map, opt(line_ending))),
-    );
-    map(preceded(opt(line_ending), parser), Converter::new)(input)
+// The stages are taken in the order they appear, whatever their headers say
+pub fn parse_almanac(input: &str) -> Result<Almanac, Box<dyn Error + '_>> {
+    let (seeds, stages) = parse_sections(input)?;
+    let stage_count = stages.len();
+    let stages: [Converter; 7] = stages
+        .try_into()
+        .map_err(|_| format!("Almanac should have 7 stages, got {stage_count}"))?;
+    let [soil, fertilizer, water, light, temperature, humidity, location] = stages;
+    Ok(Almanac {
+        seeds,
+        seed_to_soil_map: soil,
+        soil_to_fertilizer_map: fertilizer,
+        fertilizer_to_water_map: water,
+        water_to_light_map: light,
+        light_to_temperature_map: temperature,
+        temperature_to_humidity_map: humidity,
+        humidity_to_location_map: location,
+    })
 }
 
-pub fn parse_almanac(input: &str) -> Result<Almanac, Box<dyn Error + '_>> {
-    let parser = tuple((
-        parse_seeds,
-        |i| parse_converter(i, "seed-to-soil map"),
-        |i| parse_converter(i, "soil-to-fertilizer map"),
-        |i| parse_converter(i, "fertilizer-to-water map"),
-        |i| parse_converter(i, "water-to-light map"),
-        |i| parse_converter(i, "light-to-temperature map"),
-        |i| parse_converter(i, "temperature-to-humidity map"),
-        |i| parse_converter(i, "humidity-to-location map"),
-    ));
-    match map(
-        parser,
-        |(
-            seeds,
-            seed_to_soil_map,
-            soil_to_fertilizer_map,
-            fertilizer_to_water_map,
-            water_to_light_map,
-            light_to_temperature_map,
-            temperature_to_humidity_map,
-            humidity_to_location_map,
-        )| {
-            Almanac {
-                seeds,
-                seed_to_soil_map,
-                soil_to_fertilizer_map,
-                fertilizer_to_water_map,
-                water_to_light_map,
-                light_to_temperature_map,
-                temperature_to_humidity_map,
-                humidity_to_location_map,
-            }
-        },
-    )(input)
-    {
-        Ok((_, almanac)) => Ok(almanac),
-        Err(e) => Err(Box::new(e)),
+// Splits the almanac on blank lines instead of expecting the seven named maps,
+// so any number of stages can be parsed in the order they appear.
+pub fn parse_sections(input: &str) -> Result<(Vec<usize>, Vec<Converter>), Box<dyn Error + '_>> {
+    let mut sections = aoc_parse::blocks(input);
+    let seeds_section = sections.next().ok_or("Almanac should start with seeds")?;
+    let (_, seeds) = all_consuming(parse_seeds)(seeds_section.trim_end())?;
+    let stages = sections.map(parse_stage).collect::<Result<Vec<_>, _>>()?;
+    Ok((seeds, stages))
+}
+
+fn parse_stage(section: &str) -> Result<Converter, Box<dyn Error + '_>> {
+    let mut lines = section.lines();
+    let header = lines.next().ok_or("Stage should not be empty")?;
+    if !header.trim_end().ends_with(" map:") {
+        return Err(format!("Expected a \"<name> map:\" header, got {header:?}").into());
     }
+    let maps = lines
+        .map(|line| all_consuming(parse_map)(line.trim_end()).map(|(_, map)| map))
+        .collect::<Result<Vec<_>, _>>()?;
+    if maps.is_empty() {
+        return Err(format!("Stage {header:?} should have at least one mapping").into());
+    }
+    Ok(Converter::new(maps))
 }
 
 #[cfg(test)]
@@ -167,30 +158,50 @@ mod tests {
     }
 
     #[test]
-    fn test_parse_converter() {
+    fn test_parse_sections() {
         let test_input = indoc! {"
+            seeds: 79 14
+
             seed-to-soil map:
             50 98 2
             52 50 48
-            "};
 
-        let expected = Converter::new(vec![Map::new(50, 98, 2), Map::new(52, 50, 48)]);
+            soil-to-fertilizer map:
+            0 15 37
+            37 52 2
+            39 0 15
+        "};
+        let expected_seeds = vec![79, 14];
+        let expected_stages = vec![
+            Converter::new(vec![Map::new(50, 98, 2), Map::new(52, 50, 48)]),
+            Converter::new(vec![
+                Map::new(0, 15, 37),
+                Map::new(37, 52, 2),
+                Map::new(39, 0, 15),
+            ]),
+        ];
+
+        let (seeds, stages) = parse_sections(test_input).unwrap();
 
-        assert_eq!(
-            parse_converter(test_input, "seed-to-soil map"),
-            Ok(("", expected))
-        );
+        assert_eq!(seeds, expected_seeds);
+        assert_eq!(stages, expected_stages);
     }
 
     #[test]
-    fn test_parse_converter_error() {
-        let test_input = indoc! {"
-            seed-to-soil map:
-            50 98 2
-            52 50 48
-            "};
+    fn test_parse_sections_full_almanac_has_seven_stages() {
+        let (_, stages) = parse_sections(TEST_INPUT).unwrap();
+        assert_eq!(stages.len(), 7);
+    }
+
+    #[test]
+    fn test_parse_sections_error() {
+        let missing_header = "seeds: 79 14\n\n50 98 2\n";
+        let malformed_mapping = "seeds: 79 14\n\nseed-to-soil map:\n50 98\n";
+        let empty_stage = "seeds: 79 14\n\nseed-to-soil map:\n";
 
-        assert!(parse_converter(test_input, "abc").is_err());
+        assert!(parse_sections(missing_header).is_err());
+        assert!(parse_sections(malformed_mapping).is_err());
+        assert!(parse_sections(empty_stage).is_err());
     }
 
     #[test]
@@ -229,6 +240,12 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_parse_almanac_needs_seven_stages() {
+        let error = parse_almanac("seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n").unwrap_err();
+        assert_eq!(error.to_string(), "Almanac should have 7 stages, got 1");
+    }
+
     #[test]
     fn test_parse_almanac_from_file() {
         let expected = Almanac {
@@ -0,0 +1,10 @@
+use day_05_lib::almanac::Almanac;
+
+pub fn calculate(file: &str) -> usize {
+    let almanac = Almanac::from_string(file).expect("Unable to parse almanac");
+    let seed_locations = almanac.calculate_seed_locations_with_seed_ranges();
+    let lowest_seed_location = seed_locations.min().expect("No seed locations found");
+    lowest_seed_location as usize
+}
+
+aoc_core::register_solution!(5, 2, calculate);
@@ -1,4 +1,4 @@
-use lib::almanac::Almanac;
+use day_05_part_2::calculate;
 
 fn main() {
     let file = std::fs::read_to_string("./puzzle_input.txt").expect("Unable to read file");
@@ -7,13 +7,3 @@ fn main() {
         calculate(&file)
     );
 }
-
-fn calculate(file: &str) -> usize {
-    let almanac = Almanac::from_string(file).expect("Unable to parse almanac");
-    let seed_locations = almanac.calculate_seed_locations_with_seed_ranges();
-    let lowest_seed_location = seed_locations
-        .iter()
-        .min()
-        .expect("No seed locations found");
-    *lowest_seed_location
-}
@@ -0,0 +1,95 @@
+use anyhow::{bail, Context, Result};
+
+const SPEED: usize = 1; // mm/ms
+
+pub fn solve_part(input: &str) -> Result<usize> {
+    let (times, records) = parse_races(input)?;
+    let times = aoc_parse::parse_fields::<usize>(times)?;
+    let records = aoc_parse::parse_fields::<usize>(records)?;
+    if times.len() != records.len() {
+        bail!("Every race should have both a time and a record");
+    }
+
+    Ok(product_of_winners(times.into_iter().zip(records)))
+}
+
+// The numbers on each line are really one number with bad kerning
+pub fn solve_part_kerned(input: &str) -> Result<usize> {
+    let (time, record) = parse_races(input)?;
+    let time = time.replace(' ', "").parse::<usize>()?;
+    let record = record.replace(' ', "").parse::<usize>()?;
+
+    Ok(product_of_winners([(time, record)]))
+}
+
+aoc_core::register_solution!(6, 1, solve_part);
+aoc_core::register_solution!(6, 2, solve_part_kerned);
+
+fn parse_races(input: &str) -> Result<(&str, &str)> {
+    let mut lines = input.lines();
+    let times = lines
+        .next()
+        .and_then(|l| l.strip_prefix("Time:"))
+        .context("First line should start with Time:")?;
+    let records = lines
+        .next()
+        .and_then(|l| l.strip_prefix("Distance:"))
+        .context("Second line should start with Distance:")?;
+    Ok((times, records))
+}
+
+fn product_of_winners(races: impl IntoIterator<Item = (usize, usize)>) -> usize {
+    let mut product_of_winners = 1;
+
+    for (time, record) in races {
+        let mut winners = 0;
+        for i in 0..=time {
+            let distance = get_distance_travelled(i, time);
+            if beats_record(distance, record) {
+                winners += 1;
+            }
+        }
+        product_of_winners *= winners;
+    }
+
+    product_of_winners
+}
+
+fn beats_record(distance: usize, record: usize) -> bool {
+    distance > record
+}
+
+fn get_distance_travelled(time_held: usize, total_time: usize) -> usize {
+    let time_remaining = total_time - time_held;
+    let velocity = SPEED * time_held;
+    velocity * time_remaining
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_distance_travelled() {
+        assert_eq!(get_distance_travelled(0, 7), 0);
+        assert_eq!(get_distance_travelled(1, 7), 6);
+        assert_eq!(get_distance_travelled(2, 7), 10);
+        assert_eq!(get_distance_travelled(3, 7), 12);
+        assert_eq!(get_distance_travelled(4, 7), 12);
+        assert_eq!(get_distance_travelled(5, 7), 10);
+        assert_eq!(get_distance_travelled(6, 7), 6);
+        assert_eq!(get_distance_travelled(7, 7), 0);
+    }
+
+    const TEST_DATA: &str = "Time:      7  15   30\nDistance:  9  40  200";
+
+    #[test]
+    fn test_solve_part() {
+        assert_eq!(solve_part(TEST_DATA).unwrap(), 288);
+    }
+
+    #[test]
+    fn test_solve_part_kerned() {
+        assert_eq!(solve_part_kerned(TEST_DATA).unwrap(), 71503);
+    }
+}
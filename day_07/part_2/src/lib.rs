@@ -0,0 +1,476 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use anyhow::{anyhow, ensure, Context, Result};
+
+fn calculate_winnings(hands: &[Hand]) -> usize {
+    hands
+        .iter()
+        .enumerate()
+        .map(|(i, hand)| (i + 1) * hand.bid)
+        .sum()
+}
+
+pub fn total_winnings_part1(hands: &mut [Hand]) -> u64 {
+    total_winnings(hands, JokerRule::None)
+}
+
+pub fn total_winnings_part2(hands: &mut [Hand]) -> u64 {
+    total_winnings(hands, JokerRule::JIsJoker)
+}
+
+aoc_core::register_solution!(7, 2, |input| {
+    parse_hand_list(input).map(|mut hands| total_winnings_part2(&mut hands))
+});
+
+// Sorts the hands into rank order for the given rule before summing
+fn total_winnings(hands: &mut [Hand], rule: JokerRule) -> u64 {
+    hands.sort_by_cached_key(|hand| HandWithRule { hand, rule }.rank_key());
+    calculate_winnings(hands) as u64
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Clone, Copy)]
+enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl HandType {
+    fn from_hand(cards: &[usize]) -> HandType {
+        let mut card_counts = HashMap::new();
+        for card in cards {
+            let count = card_counts.entry(card).or_insert(0);
+            *count += 1;
+        }
+
+        let joker_count = card_counts.remove(&1).unwrap_or(0);
+        // If there are 5 jokers, we have a five of a kind
+        if joker_count == 5 {
+            return HandType::FiveOfAKind;
+        }
+
+        let max_key = **card_counts
+            .iter()
+            .max_by_key(|&(_k, &v)| v)
+            .expect("Frequency map should not be empty")
+            .0;
+
+        *card_counts
+            .get_mut(&max_key)
+            .expect("Max key should be in map") += joker_count;
+
+        match card_counts.len() {
+            1 => HandType::FiveOfAKind,
+            2 => {
+                if card_counts.values().any(|&v| v == 4) {
+                    HandType::FourOfAKind
+                } else {
+                    HandType::FullHouse
+                }
+            }
+            3 => {
+                if card_counts.values().any(|&v| v == 3) {
+                    HandType::ThreeOfAKind
+                } else {
+                    HandType::TwoPair
+                }
+            }
+            4 => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Hand {
+    cards: Vec<usize>,
+    bid: usize,
+    hand_type: HandType,
+    hex_value: usize,
+}
+
+impl Hand {
+    fn new(cards: Vec<usize>, bid: usize) -> Hand {
+        let hex_value = Hand::get_hex_value(&cards);
+        let hand_type = HandType::from_hand(&cards);
+        Hand {
+            cards,
+            bid,
+            hex_value,
+            hand_type,
+        }
+    }
+    fn get_hex_value(cards: &[usize]) -> usize {
+        cards.iter().fold(0, |acc, card| acc * 16 + card)
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.hand_type.cmp(&other.hand_type) {
+            Ordering::Equal => self.hex_value.cmp(&other.hex_value),
+            other => other,
+        }
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum JokerRule {
+    None,
+    JIsJoker,
+}
+
+#[derive(Debug)]
+struct HandWithRule<'a> {
+    hand: &'a Hand,
+    rule: JokerRule,
+}
+
+impl HandWithRule<'_> {
+    // Jokers are stored with a value of 1, so a hand parsed under the joker rule
+    // can still be ranked as if `J` were a Jack by mapping 1 back to 11.
+    fn rank_key(&self) -> (HandType, usize) {
+        match self.rule {
+            JokerRule::JIsJoker => (self.hand.hand_type, self.hand.hex_value),
+            JokerRule::None => {
+                let cards: Vec<usize> = self
+                    .hand
+                    .cards
+                    .iter()
+                    .map(|&card| if card == 1 { 11 } else { card })
+                    .collect();
+                (HandType::from_hand(&cards), Hand::get_hex_value(&cards))
+            }
+        }
+    }
+}
+
+// Hands are equal when they rank the same, whatever their bids
+impl PartialEq for HandWithRule<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.rank_key() == other.rank_key()
+    }
+}
+
+impl Eq for HandWithRule<'_> {}
+
+impl Ord for HandWithRule<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.rank_key().cmp(&other.rank_key())
+    }
+}
+
+impl PartialOrd for HandWithRule<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn card_value(c: char, rule: JokerRule) -> Option<u8> {
+    match c {
+        'J' => match rule {
+            JokerRule::None => Some(11),
+            JokerRule::JIsJoker => Some(1),
+        },
+        '2' => Some(2),
+        '3' => Some(3),
+        '4' => Some(4),
+        '5' => Some(5),
+        '6' => Some(6),
+        '7' => Some(7),
+        '8' => Some(8),
+        '9' => Some(9),
+        'T' => Some(10),
+        'Q' => Some(12),
+        'K' => Some(13),
+        'A' => Some(14),
+        _ => None,
+    }
+}
+
+fn from_char(c: char) -> Option<usize> {
+    card_value(c, JokerRule::JIsJoker).map(usize::from)
+}
+
+fn parse_hand(line: &str) -> Result<Hand> {
+    let (cards, bid) = line
+        .split_once(' ')
+        .with_context(|| format!("Hand should be in format \"cards bid\", got {line:?}"))?;
+    let cards = cards
+        .chars()
+        .map(|c| from_char(c).ok_or_else(|| anyhow!("Invalid card {c:?}")))
+        .collect::<Result<Vec<_>>>()?;
+    ensure!(
+        cards.len() == 5,
+        "Hand should have 5 cards, got {}",
+        cards.len()
+    );
+    let bid = bid
+        .trim()
+        .parse::<usize>()
+        .with_context(|| format!("Invalid bid {bid:?}"))?;
+    Ok(Hand::new(cards, bid))
+}
+
+pub fn parse_hand_list(input: &str) -> Result<Vec<Hand>> {
+    input.lines().map(parse_hand).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    const TEST_DATA: &str = "32T3K 765\nT55J5 684\nKK677 28\nKTJJT 220\nQQQJA 483";
+
+    #[test]
+    fn test_parse_first_hand() {
+        let expected = Hand::new(vec![3, 2, 10, 3, 13], 765);
+
+        let actual = &parse_hand_list(TEST_DATA).unwrap()[0];
+
+        assert_eq!(expected, *actual);
+    }
+
+    #[test]
+    fn test_parse_all_hands() {
+        let expected = vec![
+            Hand::new(vec![3, 2, 10, 3, 13], 765),
+            Hand::new(vec![10, 5, 5, 1, 5], 684),
+            Hand::new(vec![13, 13, 6, 7, 7], 28),
+            Hand::new(vec![13, 10, 1, 1, 10], 220),
+            Hand::new(vec![12, 12, 12, 1, 14], 483),
+        ];
+
+        let actual = parse_hand_list(TEST_DATA).unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_hand_hex_value_returns_correctly() {
+        let hand = Hand::new(vec![3, 2, 10, 3, 13], 765);
+
+        assert_eq!(hand.hex_value, 0x32A3D);
+    }
+
+    #[test]
+    fn test_hand_hex_comparison_returns_highest_hand() {
+        let hands = [Hand::new(vec![14, 2, 2], 0), Hand::new(vec![2, 2, 14], 0)];
+
+        assert!(hands[0] > hands[1]);
+        assert!(hands[1] < hands[0]);
+    }
+    #[test]
+    fn test_hand_hex_comparison_second_card_higher() {
+        let hands = [Hand::new(vec![2, 3, 2], 0), Hand::new(vec![2, 2, 14], 0)];
+
+        assert!(hands[0] > hands[1]);
+        assert!(hands[1] < hands[0]);
+    }
+
+    #[test]
+    fn test_hand_type_ordering() {
+        let hands = [Hand::new(vec![3, 3, 3], 0), Hand::new(vec![1, 14, 14], 0)];
+
+        assert!(hands[0] > hands[1]);
+        assert!(hands[1] < hands[0]);
+    }
+
+    #[test]
+    fn test_hand_type_from_hand() {
+        let hands = [
+            [2, 2, 2, 2, 2], // Five of a kind
+            [2, 2, 2, 2, 3], // Four of a kind
+            [2, 2, 2, 3, 3], // Full house
+            [4, 2, 3, 3, 3], // Three of a kind
+            [4, 4, 2, 2, 3], // Two pair
+            [2, 2, 3, 4, 5], // One pair
+            [2, 3, 4, 5, 6], // High card
+        ];
+
+        assert_eq!(HandType::FiveOfAKind, HandType::from_hand(&hands[0]));
+        assert_eq!(HandType::FourOfAKind, HandType::from_hand(&hands[1]));
+        assert_eq!(HandType::FullHouse, HandType::from_hand(&hands[2]));
+        assert_eq!(HandType::ThreeOfAKind, HandType::from_hand(&hands[3]));
+        assert_eq!(HandType::TwoPair, HandType::from_hand(&hands[4]));
+        assert_eq!(HandType::OnePair, HandType::from_hand(&hands[5]));
+        assert_eq!(HandType::HighCard, HandType::from_hand(&hands[6]));
+    }
+
+    #[test]
+    fn test_hand_type_from_hand_with_jokers() {
+        let hands = [[1, 2, 2, 2, 2], // Five of a kind
+            [1, 2, 2, 2, 3], // Four of a kind
+            [1, 2, 2, 3, 3], // Full house
+            [4, 2, 1, 3, 3], // Three of a kind
+            [1, 2, 3, 4, 5]];
+
+        assert_eq!(HandType::FiveOfAKind, HandType::from_hand(&hands[0]));
+        assert_eq!(HandType::FourOfAKind, HandType::from_hand(&hands[1]));
+        assert_eq!(HandType::FullHouse, HandType::from_hand(&hands[2]));
+        assert_eq!(HandType::ThreeOfAKind, HandType::from_hand(&hands[3]));
+        assert_eq!(HandType::OnePair, HandType::from_hand(&hands[4]));
+    }
+
+    #[test]
+    fn test_hand_sort() {
+        let mut hands = vec![
+            Hand::new(vec![2, 2, 2, 2, 3], 0), // Four of a kind
+            Hand::new(vec![2, 2, 2, 2, 2], 0), // Five of a kind
+            Hand::new(vec![1, 1, 2, 2, 3], 0), // Four of a kind (2 jokers)
+            Hand::new(vec![1, 2, 3, 3, 3], 0), // Four of a kind (1 joker)
+            Hand::new(vec![1, 1, 3, 4, 5], 0), // Three of a kind (2 jokers)
+            Hand::new(vec![1, 2, 3, 4, 5], 0), // Pair (1 joker)
+            Hand::new(vec![1, 1, 2, 2, 3], 0), // Four of a kind (2 jokers)
+            Hand::new(vec![2, 2, 2, 3, 3], 0), // Full house
+        ];
+
+        let expected = vec![
+            Hand::new(vec![1, 2, 3, 4, 5], 0), // Pair (1 joker)
+            Hand::new(vec![1, 1, 3, 4, 5], 0), // Three of a kind (2 jokers)
+            Hand::new(vec![2, 2, 2, 3, 3], 0), // Full house
+            Hand::new(vec![1, 1, 2, 2, 3], 0), // Four of a kind (2 jokers)
+            Hand::new(vec![1, 1, 2, 2, 3], 0), // Four of a kind (2 jokers)
+            Hand::new(vec![1, 2, 3, 3, 3], 0), // Four of a kind (1 joker)
+            Hand::new(vec![2, 2, 2, 2, 3], 0), // Four of a kind
+            Hand::new(vec![2, 2, 2, 2, 2], 0), // Five of a kind
+        ];
+
+        hands.sort();
+
+        assert_eq!(expected, hands);
+    }
+
+    #[test]
+    fn test_calculate_total_winnings() {
+        let mut hands = parse_hand_list(TEST_DATA).unwrap();
+
+        hands.sort();
+
+        for hand in &hands {
+            assert!(hand.cards.len() == 5);
+        }
+        assert_eq!(hands.len(), 5);
+        assert_eq!(calculate_winnings(&hands), 5905);
+    }
+
+    #[test]
+    fn test_data_correct_hands() {
+        let mut hands = parse_hand_list(TEST_DATA).unwrap();
+
+        let expected = vec![
+            Hand::new(vec![3, 2, 10, 3, 13], 765),
+            Hand::new(vec![10, 5, 5, 1, 5], 684),
+            Hand::new(vec![13, 13, 6, 7, 7], 28),
+            Hand::new(vec![13, 10, 1, 1, 10], 220),
+            Hand::new(vec![12, 12, 12, 1, 14], 483),
+        ];
+
+        assert_eq!(hands.len(), 5);
+        assert_eq!(hands, expected);
+        assert_eq!(hands[0].hand_type, HandType::OnePair);
+        assert_eq!(hands[1].hand_type, HandType::FourOfAKind);
+        assert_eq!(hands[2].hand_type, HandType::TwoPair);
+        assert_eq!(hands[3].hand_type, HandType::FourOfAKind);
+        assert_eq!(hands[4].hand_type, HandType::FourOfAKind);
+
+        hands.sort();
+        dbg!(&hands);
+
+        let expected_ordered = vec![
+            Hand::new(vec![3, 2, 10, 3, 13], 765),
+            Hand::new(vec![13, 13, 6, 7, 7], 28),
+            Hand::new(vec![10, 5, 5, 1, 5], 684),
+            Hand::new(vec![12, 12, 12, 1, 14], 483),
+            Hand::new(vec![13, 10, 1, 1, 10], 220),
+        ];
+
+        assert_eq!(hands, expected_ordered);
+    }
+
+    #[test]
+    fn test_card_value_with_joker_rule() {
+        assert_eq!(card_value('J', JokerRule::None), Some(11));
+        assert_eq!(card_value('J', JokerRule::JIsJoker), Some(1));
+        assert_eq!(card_value('A', JokerRule::JIsJoker), Some(14));
+        assert_eq!(card_value('X', JokerRule::None), None);
+    }
+
+    #[test]
+    fn test_hand_with_rule_orders_part_2_example() {
+        let hands = parse_hand_list(TEST_DATA).unwrap();
+        let mut ranked: Vec<_> = hands
+            .iter()
+            .map(|hand| HandWithRule {
+                hand,
+                rule: JokerRule::JIsJoker,
+            })
+            .collect();
+
+        ranked.sort();
+
+        let bids: Vec<_> = ranked.iter().map(|ranked| ranked.hand.bid).collect();
+        assert_eq!(bids, vec![765, 28, 684, 483, 220]);
+    }
+
+    #[test]
+    fn test_hand_with_rule_orders_without_jokers() {
+        let hands = parse_hand_list(TEST_DATA).unwrap();
+        let mut ranked: Vec<_> = hands
+            .iter()
+            .map(|hand| HandWithRule {
+                hand,
+                rule: JokerRule::None,
+            })
+            .collect();
+
+        ranked.sort();
+
+        let bids: Vec<_> = ranked.iter().map(|ranked| ranked.hand.bid).collect();
+        assert_eq!(bids, vec![765, 220, 28, 684, 483]);
+    }
+
+    #[test]
+    fn test_hand_with_rule_eq_ignores_bid() {
+        let a = Hand::new(vec![13, 10, 1, 1, 10], 220);
+        let b = Hand::new(vec![13, 10, 1, 1, 10], 5);
+        let rule = JokerRule::JIsJoker;
+
+        assert!(HandWithRule { hand: &a, rule } == HandWithRule { hand: &b, rule });
+    }
+
+    #[test]
+    fn test_total_winnings() {
+        let mut hands = parse_hand_list(TEST_DATA).unwrap();
+
+        assert_eq!(total_winnings_part1(&mut hands), 6440);
+        assert_eq!(total_winnings_part2(&mut hands), 5905);
+        // Sorting by one rule should not affect the other
+        assert_eq!(total_winnings_part1(&mut hands), 6440);
+    }
+
+    #[test]
+    fn test_parse_hand() {
+        let expected = Hand::new(vec![13, 10, 1, 1, 10], 220);
+
+        assert_eq!(parse_hand("KTJJT 220").unwrap(), expected);
+    }
+
+    #[test]
+    fn test_parse_hand_returns_error_for_invalid_hands() {
+        assert!(parse_hand("KTJJT").is_err());
+        assert!(parse_hand("KTJJX 220").is_err());
+        assert!(parse_hand("KTJJ 220").is_err());
+        assert!(parse_hand("KTJJT bid").is_err());
+    }
+}
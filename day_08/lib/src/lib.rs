@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+
+// Each node's left and right neighbours
+#[derive(Debug, Default, PartialEq)]
+pub struct Network {
+    edges: HashMap<String, (String, String)>,
+}
+
+impl Network {
+    pub fn new() -> Network {
+        Network::default()
+    }
+
+    pub fn insert(&mut self, node: &str, left: &str, right: &str) {
+        self.edges
+            .insert(node.to_string(), (left.to_string(), right.to_string()));
+    }
+
+    pub fn get(&self, node: &str) -> Option<(&str, &str)> {
+        self.edges
+            .get(node)
+            .map(|(left, right)| (left.as_str(), right.as_str()))
+    }
+
+    // The node reached from `node` by following an 'L' or 'R' instruction
+    pub fn next(&self, node: &str, instruction: char) -> &str {
+        let (left, right) = self
+            .get(node)
+            .unwrap_or_else(|| panic!("Node {node} should be in the network"));
+        match instruction {
+            'L' => left,
+            'R' => right,
+            _ => panic!("Invalid instruction {instruction:?}"),
+        }
+    }
+
+    // Steps from `start` until `is_goal` first holds, following the instructions
+    // in a loop from the start. The ghost paths are only combined with an LCM,
+    // so this also checks that taking as many steps again from the goal ends up
+    // back on that same goal.
+    pub fn cycle_length_from(
+        &self,
+        start: &str,
+        instructions: &str,
+        is_goal: impl Fn(&str) -> bool,
+    ) -> u64 {
+        assert!(
+            !instructions.is_empty(),
+            "There should be at least one instruction to follow"
+        );
+        let (steps, goal) = self.steps_until_goal(start, instructions, &is_goal);
+        let end = instructions_cycling(instructions)
+            .skip((steps % instructions.len() as u64) as usize)
+            .take(steps as usize)
+            .fold(goal, |node, instruction| self.next(node, instruction));
+        assert_eq!(
+            goal, end,
+            "Path from {start} does not cycle back to {goal} in {steps} steps"
+        );
+        steps
+    }
+
+    // Walks from `start` until `is_goal` holds. Always takes at least one step.
+    fn steps_until_goal<'a>(
+        &'a self,
+        start: &'a str,
+        instructions: &str,
+        is_goal: &impl Fn(&str) -> bool,
+    ) -> (u64, &'a str) {
+        let mut current = start;
+        for (steps, instruction) in (1..).zip(instructions_cycling(instructions)) {
+            current = self.next(current, instruction);
+            if is_goal(current) {
+                return (steps, current);
+            }
+        }
+        unreachable!("The instructions repeat forever")
+    }
+}
+
+impl<'a> FromIterator<(&'a str, &'a str, &'a str)> for Network {
+    fn from_iter<I: IntoIterator<Item = (&'a str, &'a str, &'a str)>>(iter: I) -> Network {
+        let mut network = Network::new();
+        for (node, left, right) in iter {
+            network.insert(node, left, right);
+        }
+        network
+    }
+}
+
+pub fn instructions_cycling(instructions: &str) -> impl Iterator<Item = char> + '_ {
+    instructions.chars().cycle()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ghost_network() -> Network {
+        [
+            ("11A", "11B", "XXX"),
+            ("11B", "XXX", "11Z"),
+            ("11Z", "11B", "XXX"),
+            ("22A", "22B", "XXX"),
+            ("22B", "22C", "22C"),
+            ("22C", "22Z", "22Z"),
+            ("22Z", "22B", "22B"),
+            ("XXX", "XXX", "XXX"),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn test_cycle_length_from() {
+        let network = ghost_network();
+
+        assert_eq!(
+            network.cycle_length_from("11A", "LR", |id| id.ends_with('Z')),
+            2
+        );
+        assert_eq!(
+            network.cycle_length_from("22A", "LR", |id| id.ends_with('Z')),
+            3
+        );
+    }
+
+    #[test]
+    fn test_cycle_length_from_single_goal() {
+        let network: Network = [
+            ("AAA", "BBB", "BBB"),
+            ("BBB", "AAA", "ZZZ"),
+            ("ZZZ", "ZZZ", "ZZZ"),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(network.cycle_length_from("AAA", "LLR", |id| id == "ZZZ"), 6);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not cycle back")]
+    fn test_cycle_length_from_panics_when_cycle_is_offset() {
+        let network: Network = [
+            ("AAA", "BBB", "BBB"),
+            ("BBB", "ZZZ", "ZZZ"),
+            ("ZZZ", "CCC", "CCC"),
+            ("CCC", "CCC", "CCC"),
+        ]
+        .into_iter()
+        .collect();
+
+        network.cycle_length_from("AAA", "L", |id| id == "ZZZ");
+    }
+
+    #[test]
+    #[should_panic(expected = "does not cycle back to 1Z")]
+    fn test_cycle_length_from_panics_when_cycle_reaches_another_goal() {
+        // The second goal is as far from the first as the first is from the
+        // start, so only the node reached can tell them apart
+        let network: Network = [
+            ("1A", "1B", "1B"),
+            ("1B", "1Z", "1Z"),
+            ("1Z", "2B", "2B"),
+            ("2B", "2Z", "2Z"),
+            ("2Z", "2Z", "2Z"),
+        ]
+        .into_iter()
+        .collect();
+
+        network.cycle_length_from("1A", "L", |id| id.ends_with('Z'));
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one instruction")]
+    fn test_cycle_length_from_panics_without_instructions() {
+        ghost_network().cycle_length_from("11A", "", |id| id.ends_with('Z'));
+    }
+
+    #[test]
+    fn test_instructions_cycling() {
+        let actual = instructions_cycling("LR").take(5).collect::<String>();
+
+        assert_eq!(actual, "LRLRL");
+    }
+}
@@ -0,0 +1,65 @@
+use day_08_lib::Network;
+
+pub fn solve_part(input: &str) -> u64 {
+    let (instructions, network) = parse(input);
+    get_steps_to_destination(&instructions, &network)
+}
+
+aoc_core::register_solution!(8, 1, solve_part);
+
+fn get_steps_to_destination(instructions: &str, network: &Network) -> u64 {
+    network.cycle_length_from("AAA", instructions, |node| node == "ZZZ")
+}
+
+fn parse(input: &str) -> (String, Network) {
+    let input = input.replace("\r\n", "\n");
+
+    let (instructions, map) = input
+        .split_once("\n\n")
+        .expect("Input should be split by \\n\\n");
+
+    let network = map
+        .lines()
+        .map(|l| {
+            let (key, value) = l.split_once(" = ").expect("Line must contain ' = '");
+            let (left, right) = value
+                .trim_matches(|c| c == '(' || c == ')')
+                .split_once(", ")
+                .expect("Value must contain ', '");
+            (key, left, right)
+        })
+        .collect();
+
+    (instructions.to_string(), network)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "RL\n\nAAA = (BBB, CCC)\nBBB = (DDD, EEE)\nCCC = (ZZZ, GGG)\nDDD = (DDD, DDD)\nEEE = (EEE, EEE)\nGGG = (GGG, GGG)\nZZZ = (ZZZ, ZZZ)";
+
+    #[test]
+    fn test_parse_map() {
+        let (actual_instructions, actual_map) = parse(TEST_INPUT);
+
+        assert_eq!(actual_instructions, "RL");
+        assert_eq!(actual_map.get("AAA"), Some(("BBB", "CCC")));
+        assert_eq!(actual_map.get("BBB"), Some(("DDD", "EEE")));
+        assert_eq!(actual_map.get("CCC"), Some(("ZZZ", "GGG")));
+        assert_eq!(actual_map.get("DDD"), Some(("DDD", "DDD")));
+        assert_eq!(actual_map.get("EEE"), Some(("EEE", "EEE")));
+        assert_eq!(actual_map.get("GGG"), Some(("GGG", "GGG")));
+        assert_eq!(actual_map.get("ZZZ"), Some(("ZZZ", "ZZZ")));
+    }
+
+    #[test]
+    fn test_get_steps_to_destination() {
+        let (instructions, map) = parse(TEST_INPUT);
+        let expected = 2;
+
+        let actual = get_steps_to_destination(&instructions, &map);
+
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,195 @@
+use std::collections::HashMap;
+
+use day_08_lib::Network;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+pub fn solve_part(input: &str) -> u64 {
+    search(&parse(input))
+}
+
+aoc_core::register_solution!(8, 2, solve_part);
+
+fn search(map: &Map) -> u64 {
+    let starting_nodes: Vec<&String> = map.get_starting_nodes();
+
+    let cul_steps: Vec<u64> = starting_nodes
+        .par_iter()
+        .map(|&node| {
+            map.network
+                .cycle_length_from(node, &map.instructions, |id| {
+                    map.nodes[id].is_terminal == Some(Terminal::End)
+                })
+        })
+        .collect();
+
+    aoc_math::lcm_iter(cul_steps).expect("LCM of the cycle lengths should fit in a u64")
+}
+
+fn parse(input: &str) -> Map {
+    let input = input.replace("\r\n", "\n");
+
+    let (instructions, map) = input
+        .split_once("\n\n")
+        .expect("Input should be split by \\n\\n");
+
+    let mut nodes = HashMap::new();
+    let mut network = Network::new();
+
+    map.lines().for_each(|l| {
+        let (key, value) = l.split_once(" = ").expect("Line must contain ' = '");
+        let (left, right) = value
+            .trim_matches(|c| c == '(' || c == ')')
+            .split_once(", ")
+            .expect("Value must contain ', '");
+
+        let node = Node::from_str(key);
+        nodes.insert(key.to_string(), node);
+        network.insert(key, left, right);
+    });
+
+    Map {
+        instructions: instructions.to_string(),
+        nodes,
+        network,
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Node {
+    id: String,
+    is_terminal: Option<Terminal>,
+}
+
+impl Node {
+    fn from_str(input: &str) -> Self {
+        let id = input.to_string();
+        let is_terminal = match input.chars().last() {
+            Some('A') => Some(Terminal::Start),
+            Some('Z') => Some(Terminal::End),
+            _ => None,
+        };
+        Self { id, is_terminal }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Terminal {
+    Start,
+    End,
+}
+
+struct Map {
+    instructions: String,
+    nodes: HashMap<String, Node>,
+    network: Network,
+}
+
+impl Map {
+    fn get_starting_nodes(&self) -> Vec<&String> {
+        self.nodes
+            .iter()
+            .filter_map(|(k, v)| {
+                if v.is_terminal == Some(Terminal::Start) {
+                    Some(k)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "LR\n\n11A = (11B, XXX)\n11B = (XXX, 11Z)\n11Z = (11B, XXX)\n22A = (22B, XXX)\n22B = (22C, 22C)\n22C = (22Z, 22Z)\n22Z = (22B, 22B)\nXXX = (XXX, XXX)";
+
+    #[test]
+    fn test_parse_map_instructions() {
+        let map = parse(TEST_INPUT);
+
+        assert_eq!(map.instructions, "LR");
+    }
+
+    #[test]
+    fn test_parse_map_nodes() {
+        let expected_nodes = [
+            Node {
+                id: "11A".to_string(),
+                is_terminal: Some(Terminal::Start),
+            },
+            Node {
+                id: "11B".to_string(),
+                is_terminal: None,
+            },
+            Node {
+                id: "11Z".to_string(),
+                is_terminal: Some(Terminal::End),
+            },
+            Node {
+                id: "22A".to_string(),
+                is_terminal: Some(Terminal::Start),
+            },
+            Node {
+                id: "22B".to_string(),
+                is_terminal: None,
+            },
+            Node {
+                id: "22C".to_string(),
+                is_terminal: None,
+            },
+            Node {
+                id: "22Z".to_string(),
+                is_terminal: Some(Terminal::End),
+            },
+            Node {
+                id: "XXX".to_string(),
+                is_terminal: None,
+            },
+        ];
+
+        let map = parse(TEST_INPUT);
+        let actual_nodes = map.nodes;
+
+        assert_eq!(expected_nodes[0], actual_nodes["11A"]);
+        assert_eq!(expected_nodes[1], actual_nodes["11B"]);
+        assert_eq!(expected_nodes[2], actual_nodes["11Z"]);
+        assert_eq!(expected_nodes[3], actual_nodes["22A"]);
+        assert_eq!(expected_nodes[4], actual_nodes["22B"]);
+        assert_eq!(expected_nodes[5], actual_nodes["22C"]);
+        assert_eq!(expected_nodes[6], actual_nodes["22Z"]);
+        assert_eq!(expected_nodes[7], actual_nodes["XXX"]);
+    }
+
+    #[test]
+    fn test_parse_map_edges() {
+        let expected_edges: Network = [
+            ("11A", "11B", "XXX"),
+            ("11B", "XXX", "11Z"),
+            ("11Z", "11B", "XXX"),
+            ("22A", "22B", "XXX"),
+            ("22B", "22C", "22C"),
+            ("22C", "22Z", "22Z"),
+            ("22Z", "22B", "22B"),
+            ("XXX", "XXX", "XXX"),
+        ]
+        .into_iter()
+        .collect();
+
+        let map = parse(TEST_INPUT);
+        let actual_edges = map.network;
+
+        assert_eq!(expected_edges, actual_edges);
+    }
+
+    #[test]
+    fn test_get_steps_to_destination() {
+        let map = parse(TEST_INPUT);
+        let expected = 6;
+
+        let actual = search(&map);
+
+        assert_eq!(expected, actual);
+    }
+}
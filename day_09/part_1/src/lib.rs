@@ -0,0 +1,190 @@
+use anyhow::Result;
+
+pub fn solve_part(input: &str) -> Result<i32> {
+    Ok(extrapolate_sequences(parse(input)?))
+}
+
+aoc_core::register_solution!(9, 1, solve_part);
+
+fn extrapolate_sequences(sequences: Vec<Vec<i32>>) -> i32 {
+    sequences
+        .iter()
+        .map(|seq| finite_differences(seq.to_vec()).expect("Failed to extrapolate sequence"))
+        .fold(0, |acc, seq| {
+            acc + seq.last().expect("Sequence have more than 0 elements")
+        })
+}
+
+fn parse(input: &str) -> Result<Vec<Vec<i32>>> {
+    input.lines().map(aoc_parse::parse_fields).collect()
+}
+
+fn finite_differences(mut seq: Vec<i32>) -> Option<Vec<i32>> {
+    let next = History(seq.clone()).extrapolate_next()?;
+    seq.push(next);
+    Some(seq)
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct History(Vec<i32>);
+
+impl History {
+    fn is_all_zeros(&self) -> bool {
+        self.0.iter().all(|v| *v == 0)
+    }
+
+    fn differences(&self) -> History {
+        History(self.0.windows(2).map(|v| v[1] - v[0]).collect())
+    }
+
+    // The chain starts with the original sequence and ends with the first
+    // sequence that is all zeros.
+    fn differences_until_zero(&self) -> Vec<History> {
+        let mut chain = vec![self.clone()];
+        while let Some(last) = chain.last() {
+            if last.is_all_zeros() {
+                break;
+            }
+            chain.push(last.differences());
+        }
+        chain
+    }
+
+    fn extrapolate_next(&self) -> Option<i32> {
+        self.differences_until_zero()
+            .iter()
+            .map(|history| history.0.last().copied())
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const TEST_INPUT: &str = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45";
+
+    #[test]
+    fn test_parse_returns_expected_result() {
+        let expected = vec![
+            vec![0, 3, 6, 9, 12, 15],
+            vec![1, 3, 6, 10, 15, 21],
+            vec![10, 13, 16, 21, 30, 45],
+        ];
+        let actual = parse(TEST_INPUT).expect("TEST_INPUT should parse correctly");
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_parse_returns_err_for_invalid_u32() {
+        let bad_data = "3 f 2 1\n6 f 4 9";
+
+        let actual = parse(bad_data);
+        assert!(actual.is_err())
+    }
+
+    #[test]
+    fn test_finite_differences_zeros() {
+        let expected = vec![0, 0, 0, 0];
+        let actual =
+            finite_differences(vec![0, 0, 0]).expect("Finite Differences should return Some");
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_finite_differences_linear() {
+        let expected = vec![1, 2, 3, 4];
+        let actual =
+            finite_differences(vec![1, 2, 3]).expect("Finite Differences should return Some");
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn test_finite_differences_curve() {
+        let expected = vec![1, 4, 9, 16, 25, 36];
+        let actual = finite_differences(vec![1, 4, 9, 16, 25])
+            .expect("Finite Differences should return Some");
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn test_finite_differences_test_data_one() {
+        let expected = vec![0, 3, 6, 9, 12, 15, 18];
+        let actual = finite_differences(vec![0, 3, 6, 9, 12, 15])
+            .expect("Finite Differences should return Some");
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn test_finite_differences_test_data_two() {
+        let expected = vec![1, 3, 6, 10, 15, 21, 28];
+        let actual = finite_differences(vec![1, 3, 6, 10, 15, 21])
+            .expect("Finite Differences should return Some");
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn test_finite_differences_test_data_three() {
+        let expected = vec![10, 13, 16, 21, 30, 45, 68];
+        let actual = finite_differences(vec![10, 13, 16, 21, 30, 45])
+            .expect("Finite Differences should return Some");
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn test_history_is_all_zeros() {
+        assert!(History(vec![0, 0, 0]).is_all_zeros());
+        assert!(!History(vec![0, 1, 0]).is_all_zeros());
+    }
+
+    #[test]
+    fn test_differences_until_zero_all_zeros() {
+        assert_eq!(History(vec![0, 0, 0]).differences_until_zero().len(), 1);
+    }
+
+    #[test]
+    fn test_differences_until_zero_test_data() {
+        let expected = vec![
+            History(vec![0, 3, 6, 9, 12, 15]),
+            History(vec![3, 3, 3, 3, 3]),
+            History(vec![0, 0, 0, 0]),
+        ];
+        let actual = History(vec![0, 3, 6, 9, 12, 15]).differences_until_zero();
+
+        assert_eq!(expected, actual);
+        assert_eq!(
+            History(vec![10, 13, 16, 21, 30, 45])
+                .differences_until_zero()
+                .len(),
+            5
+        );
+    }
+
+    #[test]
+    fn test_extrapolate_next() {
+        assert_eq!(
+            History(vec![1, 3, 6, 10, 15, 21]).extrapolate_next(),
+            Some(28)
+        );
+        assert_eq!(History(vec![5]).extrapolate_next(), None);
+    }
+
+    #[test]
+    fn test_extrapolate_sequences() {
+        let test_data = vec![
+            vec![0, 3, 6, 9, 12, 15],
+            vec![1, 3, 6, 10, 15, 21],
+            vec![10, 13, 16, 21, 30, 45],
+        ];
+
+        let expected = 114;
+        let actual = extrapolate_sequences(test_data);
+
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,337 @@
+use anyhow::{anyhow, Context, Result};
+use aoc_algo::Memo;
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+pub fn solve_part(input: &str) -> Result<u64> {
+    let rows = input
+        .lines()
+        .map(Row::from_str)
+        .collect::<Result<Vec<_>>>()?;
+    Ok(rows.iter().map(count_arrangements).sum())
+}
+
+aoc_core::register_solution!(12, 1, solve_part);
+
+fn count_arrangements(row: &Row) -> u64 {
+    let mut memo = Memo::new();
+    count_remaining_arrangements(&row.springs, &row.contigious_groups, &mut memo)
+}
+
+// The memo is keyed on how many springs and groups are left, which uniquely
+// identifies the suffixes being matched.
+fn count_remaining_arrangements(
+    springs: &[Spring],
+    groups: &[usize],
+    memo: &mut Memo<(usize, usize), u64>,
+) -> u64 {
+    let key = (springs.len(), groups.len());
+    memo.get_or_compute(key, |memo| match (springs.first(), groups.first()) {
+        (None, None) => 1,
+        (None, Some(_)) => 0,
+        (Some(_), None) => u64::from(!springs.contains(&Spring::Damaged)),
+        (Some(spring), Some(&group)) => {
+            let mut count = 0;
+            // Treat the current spring as operational
+            if spring != &Spring::Damaged {
+                count += count_remaining_arrangements(&springs[1..], groups, memo);
+            }
+            // Treat the current spring as the start of the next damaged group
+            if spring != &Spring::Operational && can_place_group(springs, group) {
+                let next = (group + 1).min(springs.len());
+                count += count_remaining_arrangements(&springs[next..], &groups[1..], memo);
+            }
+            count
+        }
+    })
+}
+
+// A group fits if it is not interrupted by an operational spring and is not
+// immediately followed by another damaged spring.
+fn can_place_group(springs: &[Spring], group: usize) -> bool {
+    springs.len() >= group
+        && springs[..group].iter().all(|s| s != &Spring::Operational)
+        && springs.get(group) != Some(&Spring::Damaged)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Row {
+    springs: Vec<Spring>,
+    contigious_groups: Vec<usize>,
+}
+
+impl FromStr for Row {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (head, tail) = s
+            .split_once(' ')
+            .context("Row should be seperated into springs and groups by a space")?;
+        let springs: Result<Vec<Spring>> = head
+            .chars()
+            .map(|c| Spring::try_from(c).context("Failed to parse Spring State"))
+            .collect();
+        let contigious_groups: Result<Vec<usize>> = tail
+            .split(',')
+            .map(|c| c.parse::<usize>().context("Failed to parse Group Size"))
+            .collect();
+        Ok(Row {
+            springs: springs?,
+            contigious_groups: contigious_groups?,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Spring {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+impl TryFrom<char> for Spring {
+    type Error = anyhow::Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '?' => Ok(Spring::Unknown),
+            '.' => Ok(Spring::Operational),
+            '#' => Ok(Spring::Damaged),
+            c => Err(anyhow!("{} is not a valid Spring state", c)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn count_arrangements_brute_force(row: &Row) -> u64 {
+        let unknowns: Vec<usize> = row
+            .springs
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| **s == Spring::Unknown)
+            .map(|(i, _)| i)
+            .collect();
+
+        let mut candidate = row.springs.clone();
+        let mut count = 0;
+        for mask in 0..1u64 << unknowns.len() {
+            for (bit, &i) in unknowns.iter().enumerate() {
+                candidate[i] = if mask & (1 << bit) == 0 {
+                    Spring::Operational
+                } else {
+                    Spring::Damaged
+                };
+            }
+            if validate(&candidate, &row.contigious_groups) {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    // Single pass check that a fully resolved pattern has exactly these runs of
+    // damaged springs. Any unknown spring means it cannot be checked yet.
+    fn validate(pattern: &[Spring], groups: &[usize]) -> bool {
+        let mut groups = groups.iter();
+        let mut run = 0;
+        // The trailing operational spring closes off a run at the end
+        for spring in pattern.iter().chain([Spring::Operational].iter()) {
+            match spring {
+                Spring::Damaged => run += 1,
+                Spring::Operational if run > 0 => {
+                    if groups.next() != Some(&run) {
+                        return false;
+                    }
+                    run = 0;
+                }
+                Spring::Operational => {}
+                Spring::Unknown => return false,
+            }
+        }
+        groups.next().is_none()
+    }
+
+    fn damaged_groups(springs: &[Spring]) -> Vec<usize> {
+        springs
+            .split(|s| *s != Spring::Damaged)
+            .map(|run| run.len())
+            .filter(|&len| len > 0)
+            .collect()
+    }
+
+    // Builds a row from a fully resolved pattern and then hides some of it
+    // behind unknowns, so that every generated row has at least one arrangement.
+    fn arb_solvable_row() -> impl Strategy<Value = Row> {
+        prop::collection::vec((any::<bool>(), any::<bool>()), 1..=20).prop_map(|cells| {
+            let resolved: Vec<Spring> = cells
+                .iter()
+                .map(|&(damaged, _)| {
+                    if damaged {
+                        Spring::Damaged
+                    } else {
+                        Spring::Operational
+                    }
+                })
+                .collect();
+            let contigious_groups = damaged_groups(&resolved);
+            let springs = resolved
+                .into_iter()
+                .zip(cells)
+                .map(|(spring, (_, hidden))| if hidden { Spring::Unknown } else { spring })
+                .collect();
+            Row {
+                springs,
+                contigious_groups,
+            }
+        })
+    }
+
+    // Springs and groups chosen independently, so most rows have no
+    // arrangement at all
+    fn arb_random_row() -> impl Strategy<Value = Row> {
+        let spring = prop_oneof![
+            Just(Spring::Operational),
+            Just(Spring::Damaged),
+            Just(Spring::Unknown),
+        ];
+        (
+            prop::collection::vec(spring, 1..=20),
+            prop::collection::vec(1..=6usize, 0..=5),
+        )
+            .prop_map(|(springs, contigious_groups)| Row {
+                springs,
+                contigious_groups,
+            })
+    }
+
+    fn arb_row() -> impl Strategy<Value = Row> {
+        prop_oneof![arb_solvable_row(), arb_random_row()]
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+        #[test]
+        fn test_count_arrangements_matches_brute_force(row in arb_row()) {
+            prop_assert_eq!(count_arrangements(&row), count_arrangements_brute_force(&row));
+        }
+    }
+
+    fn springs(pattern: &str) -> Vec<Spring> {
+        pattern
+            .chars()
+            .map(|c| Spring::try_from(c).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(validate(&springs("#.#.###"), &[1, 1, 3]));
+        assert!(!validate(&springs("##..###"), &[1, 1, 3]));
+        assert!(!validate(&springs("#.#.###"), &[1, 1]));
+        assert!(!validate(&springs("#.#"), &[1, 1, 3]));
+    }
+
+    #[test]
+    fn test_validate_empty_groups() {
+        assert!(validate(&springs(""), &[]));
+        assert!(validate(&springs("...."), &[]));
+        assert!(!validate(&springs("..#."), &[]));
+    }
+
+    #[test]
+    fn test_validate_all_damaged() {
+        assert!(validate(&springs("####"), &[4]));
+        assert!(!validate(&springs("####"), &[2, 2]));
+    }
+
+    #[test]
+    fn test_validate_trailing_dots() {
+        assert!(validate(&springs("#.##..."), &[1, 2]));
+        assert!(validate(&springs("...#.##"), &[1, 2]));
+    }
+
+    #[test]
+    fn test_validate_rejects_unknowns() {
+        assert!(!validate(&springs("#.?"), &[1]));
+    }
+
+    #[test]
+    fn test_validate_only_accepts_example_arrangement() {
+        // "???.### 1,1,3" only has the one arrangement
+        let row = Row::from_str("???.### 1,1,3").unwrap();
+        let valid: Vec<_> = ["...", "#..", ".#.", "..#", "##.", "#.#", ".##", "###"]
+            .into_iter()
+            .filter(|prefix| validate(&springs(&format!("{prefix}.###")), &row.contigious_groups))
+            .collect();
+
+        assert_eq!(valid, vec!["#.#"]);
+        assert_eq!(count_arrangements(&row), valid.len() as u64);
+    }
+
+    #[test]
+    fn test_count_arrangements_example_rows() {
+        let rows = [
+            ("???.### 1,1,3", 1),
+            (".??..??...?##. 1,1,3", 4),
+            ("?#?#?#?#?#?#?#? 1,3,1,6", 1),
+            ("????.#...#... 4,1,1", 1),
+            ("????.######..#####. 1,6,5", 4),
+            ("?###???????? 3,2,1", 10),
+        ];
+
+        for (input, expected) in rows {
+            let row = Row::from_str(input).unwrap();
+            assert_eq!(count_arrangements(&row), expected, "{}", input);
+            assert_eq!(count_arrangements_brute_force(&row), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_solve_part() {
+        let input = "???.### 1,1,3\n.??..??...?##. 1,1,3\n?#?#?#?#?#?#?#? 1,3,1,6\n????.#...#... 4,1,1\n????.######..#####. 1,6,5\n?###???????? 3,2,1";
+
+        assert_eq!(solve_part(input).unwrap(), 21);
+    }
+
+    #[test]
+    fn test_row_from_string() {
+        let input = "???.### 1,1,3";
+        let expected_springs = vec![
+            Spring::Unknown,
+            Spring::Unknown,
+            Spring::Unknown,
+            Spring::Operational,
+            Spring::Damaged,
+            Spring::Damaged,
+            Spring::Damaged,
+        ];
+        let expected_groups = vec![1, 1, 3];
+
+        let actual = Row::from_str(input).unwrap();
+
+        assert_eq!(expected_springs, actual.springs);
+        assert_eq!(expected_groups, actual.contigious_groups);
+    }
+
+    #[test]
+    fn test_spring_from_char_returns_correctly() {
+        let operational = '.';
+        let damaged = '#';
+        let unknown = '?';
+
+        assert_eq!(Spring::Operational, Spring::try_from(operational).unwrap());
+        assert_eq!(Spring::Damaged, Spring::try_from(damaged).unwrap());
+        assert_eq!(Spring::Unknown, Spring::try_from(unknown).unwrap());
+    }
+
+    #[test]
+    fn test_spring_from_char_returns_error_for_invalid_character() {
+        let invalid_char = '%';
+
+        assert!(Spring::try_from(invalid_char).is_err())
+    }
+}
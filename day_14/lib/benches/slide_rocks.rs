@@ -0,0 +1,103 @@
+// Compares the owned `slide_rocks` against `slide_rocks_in_place`.
+//
+// `slide_rocks` wraps the in-place version, so the only difference measured is
+// moving the column in and out of the call and, when tilting a whole grid,
+// collecting the columns into a new outer Vec. `cargo bench -p day_14_lib
+// --bench slide_rocks` reports the time of each variant in a group, and the
+// ratio between them is the speedup on that machine.
+use aoc_grid::Grid;
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use day_14_lib::{slide_rocks, slide_rocks_in_place, tilt_north_in_place, PositionState};
+use rayon::prelude::*;
+
+// A repeating pattern of round rocks, gaps and cube rocks so every slide has
+// to do some work.
+fn column(len: usize) -> Vec<PositionState> {
+    (0..len)
+        .map(|i| match i % 7 {
+            0 | 3 => PositionState::RoundRock,
+            5 => PositionState::CubeRock,
+            _ => PositionState::Empty,
+        })
+        .collect()
+}
+
+fn bench_column(c: &mut Criterion) {
+    let mut group = c.benchmark_group("slide_column_100");
+    group.bench_function("owned", |b| {
+        b.iter_batched(
+            || column(100),
+            |positions| black_box(slide_rocks(positions)),
+            BatchSize::SmallInput,
+        )
+    });
+    group.bench_function("in_place", |b| {
+        b.iter_batched_ref(
+            || column(100),
+            |positions| slide_rocks_in_place(black_box(positions)),
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+// The same column slid a million times over, the first slide settling it and
+// the rest only scanning, to see the cost of the moves add up
+fn bench_column_repeated(c: &mut Criterion) {
+    const REPEATS: usize = 1_000_000;
+
+    let mut group = c.benchmark_group("slide_column_100_x1000000");
+    group.sample_size(10);
+    group.bench_function("owned", |b| {
+        b.iter_batched(
+            || column(100),
+            |mut positions| {
+                for _ in 0..REPEATS {
+                    positions = slide_rocks(black_box(positions));
+                }
+                positions
+            },
+            BatchSize::SmallInput,
+        )
+    });
+    group.bench_function("in_place", |b| {
+        b.iter_batched_ref(
+            || column(100),
+            |positions| {
+                for _ in 0..REPEATS {
+                    slide_rocks_in_place(black_box(positions));
+                }
+            },
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+fn bench_grid(c: &mut Criterion) {
+    let columns: Vec<_> = (0..100).map(|_| column(100)).collect();
+    let grid = Grid::from_rows(columns.clone()).unwrap();
+
+    let mut group = c.benchmark_group("tilt_grid_100x100");
+    group.bench_function("owned", |b| {
+        b.iter_batched(
+            || columns.clone(),
+            |columns| {
+                let tilted: Vec<_> = columns.into_par_iter().map(slide_rocks).collect();
+                black_box(tilted)
+            },
+            BatchSize::SmallInput,
+        )
+    });
+    group.bench_function("in_place", |b| {
+        b.iter_batched_ref(
+            || grid.clone(),
+            |grid| tilt_north_in_place(black_box(grid)),
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_column, bench_column_repeated, bench_grid);
+criterion_main!(benches);
@@ -0,0 +1,494 @@
+use std::fmt;
+
+use anyhow::{bail, Error, Result};
+use aoc_core::Solution;
+use aoc_grid::Grid;
+use rayon::prelude::*;
+
+#[cfg(feature = "serde")]
+pub mod snapshot;
+
+pub const SPIN_CYCLES: usize = 1_000_000_000;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Parsed = RockGrid;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<RockGrid> {
+        RockGrid::parse(input)
+    }
+
+    fn part1(grid: &RockGrid) -> usize {
+        let mut grid = grid.clone();
+        grid.tilt_north();
+        grid.north_load()
+    }
+
+    fn part2(grid: &RockGrid) -> usize {
+        grid.clone().apply_n_cycles(SPIN_CYCLES).north_load()
+    }
+
+    fn summarize(grid: &RockGrid) -> String {
+        let count = |state| grid.0.as_slice().iter().filter(|&&p| p == state).count();
+        format!(
+            "{} columns of {}, {} round rocks, {} cube rocks",
+            grid.0.height(),
+            grid.0.width(),
+            count(PositionState::RoundRock),
+            count(PositionState::CubeRock)
+        )
+    }
+}
+
+aoc_core::register_solution!(14, Day14);
+
+// Each row of the grid is a column of the puzzle with north at the end, so a
+// round rock's load is its index + 1 and every column tilts as one slice
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct RockGrid(Grid<PositionState>);
+
+impl RockGrid {
+    pub fn parse(input: &str) -> Result<RockGrid> {
+        parse(input).map(RockGrid)
+    }
+
+    pub fn tilt_north(&mut self) {
+        tilt_north_in_place(&mut self.0);
+    }
+
+    // Tilts north, west, south then east
+    pub fn spin_cycle(&mut self) {
+        for _ in 0..4 {
+            tilt_north_in_place(&mut self.0);
+            self.0 = self.0.rotate_clockwise();
+        }
+    }
+
+    fn spun(&self) -> RockGrid {
+        let mut grid = self.clone();
+        grid.spin_cycle();
+        grid
+    }
+
+    // Skips ahead once the spin cycles start repeating. Only the spins until
+    // then are run, usually far fewer than `cycles`, so they are counted
+    // without a total.
+    pub fn apply_n_cycles(self, cycles: usize) -> RockGrid {
+        let progress = aoc_core::spinner("spin cycles");
+        aoc_algo::nth_state(self, cycles, |grid| {
+            progress.inc(1);
+            grid.spun()
+        })
+    }
+
+    pub fn north_load(&self) -> usize {
+        let width = self.0.width();
+        self.0
+            .as_slice()
+            .par_chunks(width)
+            .map(calculate_load)
+            .sum()
+    }
+}
+
+// Back to the puzzle format, with north at the top
+impl fmt::Display for RockGrid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0.rotate_counter_clockwise())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct CycleStats {
+    // Number of spin cycles before the repeating state is first reached
+    pub cycle_start: usize,
+    // Zero if no repeat was found within the allowed cycles
+    pub cycle_length: usize,
+    pub iterations_run: usize,
+}
+
+pub fn find_rock_cycle(input: &str, max_cycles: usize) -> Result<CycleStats> {
+    let grid = RockGrid::parse(input)?;
+    let stats = match aoc_algo::detect_cycle_within(grid, max_cycles, RockGrid::spun) {
+        Some(cycle) => {
+            tracing::debug!(start = cycle.start, length = cycle.length, "cycle detected");
+            CycleStats {
+                cycle_start: cycle.start,
+                cycle_length: cycle.length,
+                iterations_run: cycle.start + cycle.length,
+            }
+        }
+        None => CycleStats {
+            cycle_start: 0,
+            cycle_length: 0,
+            iterations_run: max_cycles,
+        },
+    };
+    Ok(stats)
+}
+
+// Each row of the grid is one column
+pub fn tilt_north_in_place(grid: &mut Grid<PositionState>) {
+    let width = grid.width();
+    grid.as_mut_slice()
+        .par_chunks_mut(width)
+        .for_each(slide_rocks_in_place);
+}
+
+// Turning the puzzle clockwise makes each of its columns a row, read from the
+// south end
+fn parse(input: &str) -> Result<Grid<PositionState>> {
+    let puzzle: Grid<PositionState> = input.parse()?;
+    Ok(puzzle.rotate_clockwise())
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum PositionState {
+    RoundRock,
+    CubeRock,
+    Empty,
+}
+
+impl TryFrom<char> for PositionState {
+    type Error = Error;
+
+    fn try_from(c: char) -> Result<Self> {
+        match c {
+            'O' => Ok(PositionState::RoundRock),
+            '#' => Ok(PositionState::CubeRock),
+            '.' => Ok(PositionState::Empty),
+            _ => bail!("{c:?} is not a rock or empty space"),
+        }
+    }
+}
+
+impl From<PositionState> for char {
+    fn from(state: PositionState) -> char {
+        match state {
+            PositionState::RoundRock => 'O',
+            PositionState::CubeRock => '#',
+            PositionState::Empty => '.',
+        }
+    }
+}
+
+pub fn slide_rocks(mut positions: Vec<PositionState>) -> Vec<PositionState> {
+    slide_rocks_in_place(&mut positions);
+    positions
+}
+
+pub fn slide_rocks_in_place(positions: &mut [PositionState]) {
+    // We know that our positions should not be empty
+    assert!(!positions.is_empty());
+    // We start counting from the end to enable easier cascading
+    let mut current_position = positions.len();
+    // Initialise our blocker pointer to None
+    let mut last_available_space = None;
+
+    // Loop through our positions excluding the last position
+    for _ in 0..positions.len() {
+        current_position -= 1;
+        let position_state = positions[current_position];
+
+        if let Some(last_space_index) = last_available_space {
+            // A space to slide is available
+            match position_state {
+                PositionState::RoundRock => {
+                    positions[last_space_index] = PositionState::RoundRock;
+                    positions[current_position] = PositionState::Empty;
+                    last_available_space = Some(last_space_index - 1);
+                }
+                PositionState::CubeRock => last_available_space = None,
+                PositionState::Empty => {}
+            }
+        } else {
+            // Nowhere to slide, so we only care about empty spaces
+            if position_state == PositionState::Empty {
+                last_available_space = Some(current_position);
+            }
+        }
+    }
+}
+
+fn calculate_load(positions: &[PositionState]) -> usize {
+    positions
+        .iter()
+        .enumerate()
+        .fold(0, |acc, (position, state)| {
+            if state == &PositionState::RoundRock {
+                acc + position + 1
+            } else {
+                acc
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_part1() {
+        let input = indoc! {"
+        O....#....
+        O.OO#....#
+        .....##...
+        OO.#O....O
+        .O.....O#.
+        O.#..O.#.#
+        ..O..#O..O
+        .......O..
+        #....###..
+        #OO..#....
+        "};
+        let grid = Day14::parse(input).expect("Testing input should parse");
+
+        assert_eq!(Day14::part1(&grid), 136);
+        assert_eq!(
+            Day14::summarize(&grid),
+            "10 columns of 10, 18 round rocks, 17 cube rocks"
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = indoc! {"
+        O....#....
+        O.OO#....#
+        .....##...
+        OO.#O....O
+        .O.....O#.
+        O.#..O.#.#
+        ..O..#O..O
+        .......O..
+        #....###..
+        #OO..#....
+        "};
+        let grid = Day14::parse(input).expect("Testing input should parse");
+
+        assert_eq!(Day14::part2(&grid), 64);
+    }
+
+    #[test]
+    fn test_north_load_after_cycles() {
+        let input = indoc! {"
+        O....#....
+        O.OO#....#
+        .....##...
+        OO.#O....O
+        .O.....O#.
+        O.#..O.#.#
+        ..O..#O..O
+        .......O..
+        #....###..
+        #OO..#....
+        "};
+        let grid = RockGrid::parse(input).expect("Testing input should parse");
+
+        assert_eq!(grid.clone().apply_n_cycles(1).north_load(), 87);
+        assert_eq!(grid.apply_n_cycles(1_000_000_000).north_load(), 64);
+    }
+
+    #[test]
+    fn test_north_load_matches_calculate_load() {
+        let input = indoc! {"
+        O.#
+        #..
+        .O#
+        "};
+        let grid = RockGrid::parse(input).expect("Testing input should parse");
+        let columns = parse(input).expect("Testing input should parse");
+        let expected: usize = columns.rows().map(calculate_load).sum();
+
+        assert_eq!(grid.north_load(), expected);
+        // Round rocks on the top and bottom rows, nothing has been tilted yet
+        assert_eq!(grid.north_load(), 3 + 1);
+    }
+
+    #[test]
+    fn test_rock_grid_to_string_roundtrip() {
+        let input = indoc! {"
+        O.#.
+        #..O
+        .O#.
+        "};
+        let grid = RockGrid::parse(input).expect("Testing input should parse");
+
+        assert_eq!(grid.to_string(), input);
+    }
+
+    #[test]
+    fn test_find_rock_cycle() {
+        let input = indoc! {"
+        O....#....
+        O.OO#....#
+        .....##...
+        OO.#O....O
+        .O.....O#.
+        O.#..O.#.#
+        ..O..#O..O
+        .......O..
+        #....###..
+        #OO..#....
+        "};
+        let max_cycles = 1000000000;
+        let expected = CycleStats {
+            cycle_start: 3,
+            cycle_length: 7,
+            iterations_run: 10,
+        };
+
+        let actual = find_rock_cycle(input, max_cycles).expect("Testing input should parse");
+
+        assert!(actual.cycle_length > 0);
+        assert!(actual.cycle_start < max_cycles);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_find_rock_cycle_stops_at_max_cycles() {
+        let input = indoc! {"
+        O....#....
+        O.OO#....#
+        .....##...
+        OO.#O....O
+        .O.....O#.
+        O.#..O.#.#
+        ..O..#O..O
+        .......O..
+        #....###..
+        #OO..#....
+        "};
+        let actual = find_rock_cycle(input, 5).expect("Testing input should parse");
+
+        assert_eq!(actual.cycle_length, 0);
+        assert_eq!(actual.iterations_run, 5);
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let input = indoc! {"
+        O.#
+        #..
+        .O#
+        "};
+
+        let expected = Grid::from_rows(vec![
+            vec![
+                PositionState::Empty,
+                PositionState::CubeRock,
+                PositionState::RoundRock,
+            ],
+            vec![
+                PositionState::RoundRock,
+                PositionState::Empty,
+                PositionState::Empty,
+            ],
+            vec![
+                PositionState::CubeRock,
+                PositionState::Empty,
+                PositionState::CubeRock,
+            ],
+        ])
+        .unwrap();
+
+        let actual = parse(input).expect("Testing input should not fail to parse");
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_round_rocks_slide_to_correct_positions() {
+        let line = vec![
+            PositionState::RoundRock,
+            PositionState::Empty,
+            PositionState::Empty,
+            PositionState::Empty,
+            PositionState::Empty,
+            PositionState::CubeRock,
+            PositionState::Empty,
+            PositionState::Empty,
+            PositionState::Empty,
+            PositionState::Empty,
+        ];
+        let expected = vec![
+            PositionState::Empty,
+            PositionState::Empty,
+            PositionState::Empty,
+            PositionState::Empty,
+            PositionState::RoundRock,
+            PositionState::CubeRock,
+            PositionState::Empty,
+            PositionState::Empty,
+            PositionState::Empty,
+            PositionState::Empty,
+        ];
+
+        let actual = slide_rocks(line);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_slide_rocks_in_place_matches_slide_rocks() {
+        let line = vec![
+            PositionState::RoundRock,
+            PositionState::Empty,
+            PositionState::RoundRock,
+            PositionState::CubeRock,
+            PositionState::RoundRock,
+            PositionState::Empty,
+            PositionState::Empty,
+        ];
+        let expected = slide_rocks(line.clone());
+
+        let mut actual = line;
+        slide_rocks_in_place(&mut actual);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_tilt_north_in_place() {
+        let input = indoc! {"
+        O.#
+        #..
+        .O#
+        "};
+        let mut grid = parse(input).expect("Testing input should not fail to parse");
+        let expected =
+            Grid::from_rows(grid.rows().map(|row| slide_rocks(row.to_vec())).collect()).unwrap();
+
+        tilt_north_in_place(&mut grid);
+
+        assert_eq!(grid, expected);
+    }
+
+    #[test]
+    fn test_calculate_load_returns_correct_value() {
+        let line = vec![
+            PositionState::RoundRock,
+            PositionState::Empty,
+            PositionState::Empty,
+            PositionState::Empty,
+            PositionState::Empty,
+            PositionState::CubeRock,
+            PositionState::Empty,
+            PositionState::Empty,
+            PositionState::Empty,
+            PositionState::Empty,
+        ];
+        // Before Sliding
+        let expected = 1;
+        let actual = calculate_load(&line);
+        assert_eq!(actual, expected);
+        // After Sliding
+        let expected = 5;
+        let actual = slide_rocks(line);
+        let actual = calculate_load(&actual);
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,104 @@
+use std::{fs, path::Path};
+
+use anyhow::{ensure, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::RockGrid;
+
+// Checkpoint of a grid part way through a long run of spin cycles. The grid is
+// kept in the puzzle format so snapshots can be read and edited by hand.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct GridSnapshot {
+    width: usize,
+    height: usize,
+    cycles: usize,
+    grid: String,
+}
+
+pub fn save_snapshot(grid: &RockGrid, cycles: usize, path: &Path) -> Result<()> {
+    let grid = grid.to_string();
+    let snapshot = GridSnapshot {
+        width: grid.lines().next().map_or(0, str::len),
+        height: grid.lines().count(),
+        cycles,
+        grid,
+    };
+    let json = serde_json::to_string_pretty(&snapshot)?;
+    fs::write(path, json).with_context(|| format!("Failed to write snapshot to {path:?}"))
+}
+
+pub fn load_snapshot(path: &Path) -> Result<(RockGrid, usize)> {
+    let json =
+        fs::read_to_string(path).with_context(|| format!("Failed to read snapshot {path:?}"))?;
+    let snapshot: GridSnapshot = serde_json::from_str(&json)?;
+
+    let lines: Vec<_> = snapshot.grid.lines().collect();
+    ensure!(
+        lines.len() == snapshot.height && lines.iter().all(|l| l.len() == snapshot.width),
+        "Snapshot grid does not match its {}x{} header",
+        snapshot.width,
+        snapshot.height
+    );
+
+    Ok((RockGrid::parse(&snapshot.grid)?, snapshot.cycles))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    const TEST_INPUT: &str = indoc! {"
+        O....#....
+        O.OO#....#
+        .....##...
+        OO.#O....O
+        .O.....O#.
+        O.#..O.#.#
+        ..O..#O..O
+        .......O..
+        #....###..
+        #OO..#....
+        "};
+
+    #[test]
+    fn test_save_and_load_snapshot() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("snapshot.json");
+        let grid = RockGrid::parse(TEST_INPUT).unwrap().apply_n_cycles(3);
+
+        save_snapshot(&grid, 3, &path).unwrap();
+        let (loaded, cycles) = load_snapshot(&path).unwrap();
+
+        assert_eq!(loaded, grid);
+        assert_eq!(cycles, 3);
+    }
+
+    #[test]
+    fn test_resumed_snapshot_matches_uninterrupted_run() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("snapshot.json");
+        let grid = RockGrid::parse(TEST_INPUT).unwrap();
+        save_snapshot(&grid.clone().apply_n_cycles(2), 2, &path).unwrap();
+
+        let (resumed, cycles) = load_snapshot(&path).unwrap();
+
+        assert_eq!(
+            resumed.apply_n_cycles(1000 - cycles).north_load(),
+            grid.apply_n_cycles(1000).north_load()
+        );
+    }
+
+    #[test]
+    fn test_load_snapshot_rejects_mismatched_header() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("snapshot.json");
+        fs::write(
+            &path,
+            r#"{"width": 3, "height": 1, "cycles": 0, "grid": "O.#.\n"}"#,
+        )
+        .unwrap();
+
+        assert!(load_snapshot(&path).is_err());
+    }
+}
@@ -0,0 +1,47 @@
+pub fn sum_hash_all_steps(sequence: &str) -> u64 {
+    sequence
+        .split(',')
+        .map(|step| hash_step(step.trim()) as u64)
+        .sum()
+}
+
+aoc_core::register_solution!(15, 1, sum_hash_all_steps);
+
+fn hash_step(input: &str) -> usize {
+    input
+        .chars()
+        .map(|c| c as usize)
+        .fold(0, |acc, ascii_value| ((acc + ascii_value) * 17) % 256)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sum_hash_all_steps() {
+        let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+        assert_eq!(sum_hash_all_steps(input), 1320);
+    }
+
+    #[test]
+    fn test_sum_hash_all_steps_ignores_trailing_newline() {
+        let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7\n";
+        assert_eq!(sum_hash_all_steps(input), 1320);
+    }
+
+    #[test]
+    fn test_hash_step_hash_equals_52() {
+        assert_eq!(hash_step("HASH"), 52);
+    }
+
+    #[test]
+    fn test_hash_step_rn_equals_30() {
+        assert_eq!(hash_step("rn=1"), 30);
+    }
+
+    #[test]
+    fn test_hash_step_empty_equals_0() {
+        assert_eq!(hash_step(""), 0);
+    }
+}
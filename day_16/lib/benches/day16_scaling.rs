@@ -0,0 +1,50 @@
+// Measures how get_max_energized_tiles scales with the size of a square grid.
+//
+// Every edge tile is an entry point and each beam can visit every tile, so the
+// expected cost is O(W * H) per entry point, O(N^3) for an N x N grid overall.
+// The tiles are random but seeded, so runs are comparable with each other.
+//
+// `cargo bench -p day_16_lib` reports the time for each size. Doubling the size
+// should cost about 8x, so a bigger jump means the beams' seen sets have
+// stopped fitting in cache.
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use day_16_lib::{get_max_energized_tiles, Grid};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+const SIZES: [usize; 4] = [10, 50, 100, 200];
+
+// Roughly the mix of the puzzle input, which is mostly empty space
+fn random_grid(size: usize, rng: &mut StdRng) -> Grid {
+    let input: String = (0..size)
+        .map(|_| {
+            let mut row: String = (0..size)
+                .map(|_| match rng.gen_range(0..10) {
+                    0 => '/',
+                    1 => '\\',
+                    2 => '-',
+                    3 => '|',
+                    _ => '.',
+                })
+                .collect();
+            row.push('\n');
+            row
+        })
+        .collect();
+    input.parse().expect("Generated grid should be valid")
+}
+
+fn bench_grid_sizes(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(16);
+    let mut group = c.benchmark_group("max_energized_tiles");
+    group.sample_size(10);
+    for size in SIZES {
+        let grid = random_grid(size, &mut rng);
+        group.bench_with_input(BenchmarkId::from_parameter(size), &grid, |b, grid| {
+            b.iter(|| get_max_energized_tiles(black_box(grid)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_grid_sizes);
+criterion_main!(benches);
@@ -0,0 +1,466 @@
+use std::{collections::HashSet, fmt, str::FromStr};
+
+use anyhow::{anyhow, Error, Ok, Result};
+use aoc_core::Solution;
+use aoc_grid::{Direction, GridBitSet, Point, RayAction};
+use rayon::prelude::*;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Parsed = Grid;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Grid> {
+        input.parse::<Grid>()
+    }
+
+    // The beam enters the top left corner heading east
+    fn part1(grid: &Grid) -> usize {
+        get_energized_tiles(grid, Point::new(0, 0), Direction::West).len()
+    }
+
+    fn part2(grid: &Grid) -> usize {
+        get_max_energized_tiles(grid)
+    }
+
+    fn summarize(grid: &Grid) -> String {
+        let count = |tile| grid.tiles.as_slice().iter().filter(|&&t| t == tile).count();
+        format!(
+            "{}x{} tiles, {} '/', {} '\\', {} '-', {} '|', {} empty",
+            grid.extents.x + 1,
+            grid.extents.y + 1,
+            count(Tile::MirrorForward),
+            count(Tile::MirrorBackward),
+            count(Tile::SplitterHorizontal),
+            count(Tile::SplitterVertical),
+            count(Tile::Empty)
+        )
+    }
+}
+
+aoc_core::register_solution!(16, Day16);
+
+// Tries every entry point along the edges of the grid
+pub fn get_max_energized_tiles(grid: &Grid) -> usize {
+    // All origins from left edge
+    let left_edge = (0..=grid.extents.y)
+        .map(|y| (Point::new(0, y), Direction::West))
+        .collect::<Vec<_>>();
+    // All origins from right edge
+    let mut right_edge = (0..=grid.extents.y)
+        .map(|y| (Point::new(grid.extents.x, y), Direction::East))
+        .collect::<Vec<_>>();
+    // All origins from top edge
+    let mut top_edge = (0..=grid.extents.x)
+        .map(|x| (Point::new(x, 0), Direction::North))
+        .collect::<Vec<_>>();
+    // All origins from bottom edge
+    let mut bottom_edge = (0..=grid.extents.x)
+        .map(|x| (Point::new(x, grid.extents.y), Direction::South))
+        .collect::<Vec<_>>();
+    // Combined edges
+    let mut edges = left_edge;
+    edges.append(&mut right_edge);
+    edges.append(&mut top_edge);
+    edges.append(&mut bottom_edge);
+
+    let progress = aoc_core::progress("entry points", edges.len() as u64);
+    edges
+        .par_iter()
+        .map(|(start, origin)| {
+            let energized = get_energized_tiles(grid, *start, *origin).len();
+            progress.inc(1);
+            energized
+        })
+        .max()
+        .unwrap()
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Tile {
+    Empty,
+    MirrorForward,
+    MirrorBackward,
+    SplitterHorizontal,
+    SplitterVertical,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self> {
+        match value {
+            '.' => Ok(Tile::Empty),
+            '/' => Ok(Tile::MirrorForward),
+            '\\' => Ok(Tile::MirrorBackward),
+            '-' => Ok(Tile::SplitterHorizontal),
+            '|' => Ok(Tile::SplitterVertical),
+            _ => Err(anyhow!("Failed to parse Tile from char")),
+        }
+    }
+}
+
+impl Tile {
+    // Where a beam heading in `heading` goes once it reaches this tile
+    fn deflect(self, heading: Direction) -> RayAction {
+        match (self, heading) {
+            (Tile::Empty, _) => RayAction::Continue,
+            (Tile::MirrorForward, Direction::North) => RayAction::Turn(Direction::East),
+            (Tile::MirrorForward, Direction::East) => RayAction::Turn(Direction::North),
+            (Tile::MirrorForward, Direction::South) => RayAction::Turn(Direction::West),
+            (Tile::MirrorForward, Direction::West) => RayAction::Turn(Direction::South),
+            (Tile::MirrorBackward, Direction::North) => RayAction::Turn(Direction::West),
+            (Tile::MirrorBackward, Direction::East) => RayAction::Turn(Direction::South),
+            (Tile::MirrorBackward, Direction::South) => RayAction::Turn(Direction::East),
+            (Tile::MirrorBackward, Direction::West) => RayAction::Turn(Direction::North),
+            (Tile::SplitterHorizontal, Direction::North | Direction::South) => {
+                tracing::trace!(?heading, "beam split");
+                RayAction::Split(Direction::East, Direction::West)
+            }
+            (Tile::SplitterVertical, Direction::East | Direction::West) => {
+                tracing::trace!(?heading, "beam split");
+                RayAction::Split(Direction::North, Direction::South)
+            }
+            (Tile::SplitterHorizontal | Tile::SplitterVertical, _) => RayAction::Continue,
+        }
+    }
+}
+
+impl From<Tile> for char {
+    fn from(tile: Tile) -> Self {
+        match tile {
+            Tile::Empty => '.',
+            Tile::MirrorForward => '/',
+            Tile::MirrorBackward => '\\',
+            Tile::SplitterHorizontal => '-',
+            Tile::SplitterVertical => '|',
+        }
+    }
+}
+
+fn get_energized_tiles(grid: &Grid, start: Point, origin: Direction) -> GridBitSet {
+    let mut energized = GridBitSet::new(grid.tiles.width(), grid.tiles.height());
+    energized.extend(
+        trace_beam(grid, start, origin)
+            .into_iter()
+            .map(|(position, _)| position),
+    );
+    energized
+}
+
+// How many steps all the beams take together, counting a tile once for each
+// heading it is crossed in, as a measure of how much work a grid takes
+pub fn count_beam_steps(grid: &Grid, start: Point, origin: Direction) -> usize {
+    trace_beam(grid, start, origin).len()
+}
+
+// Every distinct (position, heading) pair visited by any beam segment
+fn trace_beam(grid: &Grid, start: Point, origin: Direction) -> HashSet<(Point, Direction)> {
+    let _span = tracing::debug_span!("beam", ?start, ?origin).entered();
+    // The beam heads away from the side it entered from
+    grid.tiles
+        .cast_ray(start, origin.opposite(), |tile, heading| {
+            tile.deflect(heading)
+        })
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Grid {
+    tiles: aoc_grid::Grid<Tile>,
+    // The largest x and y in the grid
+    extents: Point,
+}
+
+// Fixtures for tests
+#[cfg(test)]
+impl Grid {
+    fn from_dimensions(width: usize, height: usize) -> Grid {
+        GridBuilder::new(width, height).build()
+    }
+
+    fn from_tile_chars(s: &str) -> Result<Grid> {
+        s.parse()
+    }
+}
+
+// Builds test grids tile by tile, avoiding escaped backslashes in fixtures
+#[cfg(test)]
+struct GridBuilder {
+    width: usize,
+    height: usize,
+    tiles: Vec<Tile>,
+}
+
+#[cfg(test)]
+impl GridBuilder {
+    fn new(width: usize, height: usize) -> GridBuilder {
+        GridBuilder {
+            width,
+            height,
+            tiles: vec![Tile::Empty; width * height],
+        }
+    }
+
+    fn set(mut self, x: usize, y: usize, tile: Tile) -> Self {
+        assert!(
+            x < self.width && y < self.height,
+            "({x}, {y}) is outside the grid"
+        );
+        self.tiles[y * self.width + x] = tile;
+        self
+    }
+
+    fn build(self) -> Grid {
+        let tiles = aoc_grid::Grid::from_vec(self.width, self.tiles)
+            .expect("The builder always fills whole rows");
+        Grid::from_tiles(tiles)
+    }
+}
+
+// The puzzle format, one char per tile and one row per line
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.tiles)
+    }
+}
+
+impl FromStr for Grid {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        s.parse().map(Grid::from_tiles)
+    }
+}
+
+impl Grid {
+    fn from_tiles(tiles: aoc_grid::Grid<Tile>) -> Grid {
+        let extents = Point::new(tiles.width() - 1, tiles.height() - 1);
+        Grid { tiles, extents }
+    }
+
+    // Puts `new_tile` at (x, y) and returns the tile it replaced, e.g. to try
+    // out "what if" changes to a grid
+    pub fn replace_tile(&mut self, x: usize, y: usize, new_tile: Tile) -> Result<Tile> {
+        let tile = self
+            .tiles
+            .get_mut(x, y)
+            .ok_or_else(|| anyhow!("Position ({x}, {y}) is outside the grid"))?;
+        Ok(std::mem::replace(tile, new_tile))
+    }
+
+    // Leaves the grid untouched if either point is outside it
+    pub fn swap_tiles(&mut self, a: Point, b: Point) -> Result<()> {
+        let tile_a = *self
+            .tiles
+            .get(a.x, a.y)
+            .ok_or_else(|| anyhow!("Position ({}, {}) is outside the grid", a.x, a.y))?;
+        let tile_b = self.replace_tile(b.x, b.y, tile_a)?;
+        self.tiles[a] = tile_b;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    const EXAMPLE: &str = indoc! {r#"
+        .|...\....
+        |.-.\.....
+        .....|-...
+        ........|.
+        ..........
+        .........\
+        ..../.\\..
+        .-.-/..|..
+        .|....-|.\
+        ..//.|....
+        "#};
+
+    #[test]
+    fn test_part1() {
+        let grid = Day16::parse(EXAMPLE).expect("Test Input should be valid");
+
+        assert_eq!(Day16::part1(&grid), 46);
+        assert_eq!(
+            Day16::summarize(&grid),
+            "10x10 tiles, 4 '/', 6 '\\', 5 '-', 8 '|', 77 empty"
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        let grid = EXAMPLE.parse::<Grid>().expect("Test Input should be valid");
+        assert_eq!(grid.extents, Point::new(9, 9));
+
+        assert_eq!(Day16::part2(&grid), 51);
+    }
+
+    #[test]
+    fn test_max_energized_tiles_from_side() {
+        // Only the beam entering from the right reaches all three tiles up to
+        // and including the splitter
+        let grid = GridBuilder::new(4, 1)
+            .set(1, 0, Tile::SplitterVertical)
+            .build();
+
+        assert_eq!(
+            get_energized_tiles(&grid, Point::new(0, 0), Direction::West).len(),
+            2
+        );
+        assert_eq!(get_max_energized_tiles(&grid), 3);
+    }
+
+    #[test]
+    fn test_energized_tiles() {
+        // We need to manually escape the backslash in indoc
+        let input = indoc! {"
+        .|-
+        /|/
+        /-/
+        "};
+        let grid = input.parse::<Grid>().expect("Test Input should be valid");
+        let energized_tiles = get_energized_tiles(&grid, Point::new(0, 0), Direction::West);
+        let expected_count = 7;
+        assert_eq!(energized_tiles.len(), expected_count);
+    }
+
+    #[test]
+    fn test_count_beam_steps() {
+        let grid = EXAMPLE.parse::<Grid>().expect("Test Input should be valid");
+
+        let energized_tiles = get_energized_tiles(&grid, Point::new(0, 0), Direction::West);
+        let beam_steps = count_beam_steps(&grid, Point::new(0, 0), Direction::West);
+
+        assert_eq!(energized_tiles.len(), 46);
+        assert_eq!(beam_steps, 51);
+    }
+
+    #[test]
+    fn test_replace_tile_changes_energized_tiles() {
+        let mut grid = EXAMPLE.parse::<Grid>().expect("Test Input should be valid");
+        assert_eq!(
+            get_energized_tiles(&grid, Point::new(0, 0), Direction::West).len(),
+            46
+        );
+
+        let old_tile = grid
+            .replace_tile(0, 0, Tile::MirrorForward)
+            .expect("(0, 0) should be in the grid");
+
+        assert_eq!(old_tile, Tile::Empty);
+        // The mirror now deflects the beam straight off the top edge
+        assert_eq!(
+            get_energized_tiles(&grid, Point::new(0, 0), Direction::West).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_replace_tile_returns_error_out_of_bounds() {
+        let mut grid = ".|\n-."
+            .parse::<Grid>()
+            .expect("Test Input should be valid");
+        assert!(grid.replace_tile(2, 0, Tile::Empty).is_err());
+    }
+
+    #[test]
+    fn test_swap_tiles() {
+        let mut grid = ".|\n-."
+            .parse::<Grid>()
+            .expect("Test Input should be valid");
+        grid.swap_tiles(Point::new(1, 0), Point::new(0, 1)).unwrap();
+        assert_eq!(grid.tiles[(1, 0)], Tile::SplitterHorizontal);
+        assert_eq!(grid.tiles[(0, 1)], Tile::SplitterVertical);
+
+        grid.swap_tiles(Point::new(0, 0), Point::new(0, 0)).unwrap();
+        assert_eq!(grid.tiles[(0, 0)], Tile::Empty);
+    }
+
+    #[test]
+    fn test_swap_tiles_returns_error_out_of_bounds() {
+        let mut grid = ".|\n-."
+            .parse::<Grid>()
+            .expect("Test Input should be valid");
+        let error = grid
+            .swap_tiles(Point::new(1, 0), Point::new(2, 0))
+            .unwrap_err();
+        assert_eq!(error.to_string(), "Position (2, 0) is outside the grid");
+        assert!(grid.swap_tiles(Point::new(0, 2), Point::new(0, 0)).is_err());
+        assert_eq!(grid.to_string(), ".|\n-.\n");
+    }
+
+    #[test]
+    fn test_grid_to_string_roundtrip() {
+        let grid = Grid::from_tile_chars(EXAMPLE).expect("Test Input should be valid");
+
+        assert_eq!(grid.to_string(), EXAMPLE);
+        assert_eq!(Grid::from_tile_chars(&grid.to_string()).unwrap(), grid);
+    }
+
+    #[test]
+    fn test_grid_from_dimensions() {
+        let mut grid = Grid::from_dimensions(3, 2);
+        grid.replace_tile(2, 0, Tile::MirrorBackward).unwrap();
+        grid.replace_tile(0, 1, Tile::SplitterVertical).unwrap();
+
+        assert_eq!(grid.to_string(), "..\\\n|..\n");
+        assert_eq!(Grid::from_tile_chars(&grid.to_string()).unwrap(), grid);
+    }
+
+    #[test]
+    fn test_grid_builder_matches_parsed_grid() {
+        let built = GridBuilder::new(3, 3)
+            .set(1, 1, Tile::MirrorForward)
+            .set(2, 0, Tile::MirrorBackward)
+            .set(0, 2, Tile::SplitterHorizontal)
+            .build();
+        let parsed = "..\\\n./.\n-..\n"
+            .parse::<Grid>()
+            .expect("Test Input should be valid");
+
+        assert_eq!(built, parsed);
+    }
+
+    #[test]
+    #[should_panic(expected = "outside the grid")]
+    fn test_grid_builder_set_out_of_bounds() {
+        GridBuilder::new(3, 3).set(3, 0, Tile::SplitterVertical);
+    }
+
+    #[test]
+    fn test_parse_tile_from_char() {
+        assert_eq!(Tile::try_from('.').unwrap(), Tile::Empty);
+        assert_eq!(Tile::try_from('/').unwrap(), Tile::MirrorForward);
+        assert_eq!(Tile::try_from('\\').unwrap(), Tile::MirrorBackward);
+        assert_eq!(Tile::try_from('-').unwrap(), Tile::SplitterHorizontal);
+        assert_eq!(Tile::try_from('|').unwrap(), Tile::SplitterVertical);
+    }
+
+    #[test]
+    fn test_parse_tile_from_char_returns_error_invalid_characters() {
+        assert!(Tile::try_from('d').is_err());
+    }
+
+    #[test]
+    fn test_parse_grid_from_string() {
+        // We need to manually escape the backslash in indoc
+        let input = indoc! {"
+        .|/
+        -..
+        |.\\
+        "};
+        let actual = input.parse::<Grid>().expect("Test Input should be valid");
+        let expected = aoc_grid::Grid::from_rows(vec![
+            vec![Tile::Empty, Tile::SplitterVertical, Tile::MirrorForward],
+            vec![Tile::SplitterHorizontal, Tile::Empty, Tile::Empty],
+            vec![Tile::SplitterVertical, Tile::Empty, Tile::MirrorBackward],
+        ])
+        .unwrap();
+        let expected_extents = Point::new(2, 2);
+        assert_eq!(actual.extents, expected_extents);
+        assert_eq!(actual.tiles, expected);
+    }
+}
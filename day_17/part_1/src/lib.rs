@@ -0,0 +1,284 @@
+use std::{ops::Index, str::FromStr};
+
+use anyhow::{Context, Result};
+
+pub fn solve_part(input: &str) -> Result<usize> {
+    let grid = input.parse::<Grid>()?;
+    let end = (grid.width - 1, grid.height - 1).into();
+    let search = SearchParameters {
+        start: (0, 0).into(),
+        end,
+        max_movement: 3,
+    };
+    grid.find_lowest_heatloss(search)
+        .context("There should be a correct path")
+}
+
+aoc_core::register_solution!(17, 1, solve_part);
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+struct Coordinate {
+    x: usize,
+    y: usize,
+}
+
+impl From<(usize, usize)> for Coordinate {
+    fn from(value: (usize, usize)) -> Self {
+        Self {
+            x: value.0,
+            y: value.1,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+enum Direction {
+    Horizontal,
+    Vertical,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+struct Move {
+    position: Coordinate,
+    direction: Option<Direction>,
+}
+
+impl Move {
+    fn new(position: Coordinate, direction: Option<Direction>) -> Self {
+        Self {
+            position,
+            direction,
+        }
+    }
+}
+
+struct SearchParameters {
+    start: Coordinate,
+    end: Coordinate,
+    max_movement: usize,
+}
+
+struct Grid {
+    width: usize,
+    height: usize,
+    cells: aoc_grid::Grid<u8>,
+}
+
+impl Grid {
+    // A* over moves, so the direction we arrived from is part of each node.
+    // Every block loses at least 1 heat, so the Manhattan distance to the end
+    // never overestimates.
+    fn find_lowest_heatloss(&self, search: SearchParameters) -> Option<usize> {
+        let first_move = Move::new(search.start, None);
+        let end = search.end;
+        // None if no route was discovered, which shouldn't ever happen with our
+        // test data
+        aoc_graph::astar(
+            first_move,
+            |&m| self.successors(m, search.max_movement),
+            |m| m.position.x.abs_diff(end.x) + m.position.y.abs_diff(end.y),
+            |m| m.position == end,
+        )
+        .map(|(_, heat_loss)| heat_loss)
+    }
+
+    fn successors(&self, previous_move: Move, max_movement: usize) -> Vec<(Move, usize)> {
+        let mut successors = Vec::new();
+        // For tracking the heat lost so far in each direction E W S N
+        let mut culm_costs = (0, 0, 0, 0);
+
+        // We need to run the loop from 1 to the max movement depth
+        for d in 1..=max_movement {
+            // Handle Horizontal movements
+            if previous_move.direction != Some(Direction::Horizontal) {
+                // Positive Direction (East)
+                if let Some(pos) =
+                    self.bounded_add_coordinates(previous_move.position, Coordinate { x: d, y: 0 })
+                {
+                    // We need to update the culm score in this direction
+                    culm_costs.0 += usize::from(self[pos]);
+                    // Create a new move
+                    let new_move = Move::new(pos, Some(Direction::Horizontal));
+                    successors.push((new_move, culm_costs.0));
+                }
+                // Negative Direction (West)
+                if let Some(pos) =
+                    self.bounded_sub_coordinates(previous_move.position, Coordinate { x: d, y: 0 })
+                {
+                    // We need to update the culm score in this direction
+                    culm_costs.1 += usize::from(self[pos]);
+                    // Create a new move
+                    let new_move = Move::new(pos, Some(Direction::Horizontal));
+                    successors.push((new_move, culm_costs.1));
+                }
+            }
+            if previous_move.direction != Some(Direction::Vertical) {
+                // Positive Direction (South)
+                if let Some(pos) =
+                    self.bounded_add_coordinates(previous_move.position, Coordinate { x: 0, y: d })
+                {
+                    culm_costs.2 += usize::from(self[pos]);
+                    let new_move = Move::new(pos, Some(Direction::Vertical));
+                    successors.push((new_move, culm_costs.2));
+                }
+                // Negative Direction (North)
+                if let Some(pos) =
+                    self.bounded_sub_coordinates(previous_move.position, Coordinate { x: 0, y: d })
+                {
+                    culm_costs.3 += usize::from(self[pos]);
+                    let new_move = Move::new(pos, Some(Direction::Vertical));
+                    successors.push((new_move, culm_costs.3));
+                }
+            }
+        }
+
+        successors
+    }
+
+    fn bounded_add_coordinates(&self, a: Coordinate, b: Coordinate) -> Option<Coordinate> {
+        if a.x + b.x < self.width && a.y + b.y < self.height {
+            Some((a.x + b.x, a.y + b.y).into())
+        } else {
+            None
+        }
+    }
+
+    fn bounded_sub_coordinates(&self, a: Coordinate, b: Coordinate) -> Option<Coordinate> {
+        let x = a.x.checked_sub(b.x);
+        let y = a.y.checked_sub(b.y);
+        if let (Some(x), Some(y)) = (x, y) {
+            Some((x, y).into())
+        } else {
+            None
+        }
+    }
+}
+
+impl FromStr for Grid {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Grid> {
+        let cells = aoc_grid::Grid::from_digits(s)?;
+        Ok(Grid {
+            width: cells.width(),
+            height: cells.height(),
+            cells,
+        })
+    }
+}
+
+impl Index<Coordinate> for Grid {
+    type Output = u8;
+
+    fn index(&self, index: Coordinate) -> &Self::Output {
+        &self.cells[(index.x, index.y)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_parse_grid() {
+        let input = indoc! {"
+        2413432311323
+        3215453535623
+        3255245654254
+        3446585845452
+        4546657867536
+        1438598798454
+        4457876987766
+        3637877979653
+        4654967986887
+        4564679986453
+        1224686865563
+        2546548887735
+        4322674655533
+        "};
+
+        let grid = input.parse::<Grid>().expect("Input should be valid grid");
+        assert_eq!(grid.width, 13);
+        assert_eq!(grid.height, 13);
+        assert_eq!(grid.cells.as_slice().len(), 13 * 13);
+        // First row
+        assert_eq!(grid[(0, 0).into()], 2);
+        assert_eq!(grid[(1, 0).into()], 4);
+        assert_eq!(grid[(2, 0).into()], 1);
+        assert_eq!(grid[(3, 0).into()], 3);
+        assert_eq!(grid[(4, 0).into()], 4);
+        assert_eq!(grid[(5, 0).into()], 3);
+        assert_eq!(grid[(6, 0).into()], 2);
+        assert_eq!(grid[(7, 0).into()], 3);
+        assert_eq!(grid[(8, 0).into()], 1);
+        assert_eq!(grid[(9, 0).into()], 1);
+        assert_eq!(grid[(10, 0).into()], 3);
+        assert_eq!(grid[(11, 0).into()], 2);
+        assert_eq!(grid[(12, 0).into()], 3);
+    }
+
+    #[test]
+    fn test_successors() {
+        let input = indoc! {"
+        2413432311323
+        3215453535623
+        3255245654254
+        3446585845452
+        4546657867536
+        1438598798454
+        4457876987766
+        3637877979653
+        4654967986887
+        4564679986453
+        1224686865563
+        2546548887735
+        4322674655533
+        "};
+
+        let grid = input.parse::<Grid>().expect("Input should be valid grid");
+        let start = (0, 0).into();
+        let move_1 = Move::new(start, None);
+        let successors: Vec<(Move, usize)> = grid.successors(move_1, 3);
+        let expected = vec![
+            (Move::new((1, 0).into(), Some(Direction::Horizontal)), 4),
+            (Move::new((0, 1).into(), Some(Direction::Vertical)), 3),
+            (Move::new((2, 0).into(), Some(Direction::Horizontal)), 5),
+            (Move::new((0, 2).into(), Some(Direction::Vertical)), 6),
+            (Move::new((3, 0).into(), Some(Direction::Horizontal)), 8),
+            (Move::new((0, 3).into(), Some(Direction::Vertical)), 9),
+        ];
+        assert_eq!(successors.len(), 6);
+        assert_eq!(successors, expected);
+    }
+
+    #[test]
+    fn test_find_lowest_heatloss() {
+        let input = indoc! {"
+        2413432311323
+        3215453535623
+        3255245654254
+        3446585845452
+        4546657867536
+        1438598798454
+        4457876987766
+        3637877979653
+        4654967986887
+        4564679986453
+        1224686865563
+        2546548887735
+        4322674655533
+        "};
+
+        let grid = input.parse::<Grid>().expect("Input should be valid grid");
+        let search = SearchParameters {
+            start: (0, 0).into(),
+            end: (12, 12).into(),
+            max_movement: 3,
+        };
+        let result = grid
+            .find_lowest_heatloss(search)
+            .expect("There should be a correct path");
+        assert_eq!(result, 102);
+    }
+}
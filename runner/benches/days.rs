@@ -0,0 +1,68 @@
+// Benchmarks every registered part against its puzzle input, falling back to
+// the example when the input is not there. Days written as a `Solution` also
+// have parsing and each part measured on their own, which is what tells
+// whether a change like day 14's rayon tilting actually pays off.
+//
+// Run a single day with `cargo bench -p aoc2023 --bench days -- day_14`.
+// The solvers share rayon's global pool, so `RAYON_NUM_THREADS=2` in front of
+// that measures how a day scales, as --threads does for the runner.
+use std::fs;
+
+use aoc_core::{registrations, Solution, DEFAULT_YEAR};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+#[path = "../src/paths.rs"]
+mod paths;
+
+include!("../src/days.rs");
+
+fn read_input(year: u16, day: u8, part: u8) -> String {
+    let example = paths::example_dir(year, day).join(format!("part_{part}.txt"));
+    fs::read_to_string(paths::input_path(year, day, part))
+        .or_else(|_| fs::read_to_string(example))
+        .unwrap_or_else(|_| panic!("{year} day {day} part {part} has no input or example"))
+}
+
+// Days of later years are prefixed with their year
+fn day_name(year: u16, day: u8) -> String {
+    if year == DEFAULT_YEAR {
+        format!("day_{day:02}")
+    } else {
+        format!("{year}_day_{day:02}")
+    }
+}
+
+fn bench_totals(c: &mut Criterion) {
+    let mut group = c.benchmark_group("total");
+    group.sample_size(10);
+    for registration in registrations() {
+        let (year, day, part) = (registration.year, registration.day, registration.part);
+        let input = read_input(year, day, part);
+        group.bench_function(format!("{}_part_{part}", day_name(year, day)), |b| {
+            b.iter(|| (registration.solve)(black_box(&input)))
+        });
+    }
+    group.finish();
+}
+
+// Both parts share the parsed input, as they do in the runner
+fn bench_phases<S: Solution>(c: &mut Criterion, year: u16, day: u8) {
+    let input = read_input(year, day, 1);
+    let parsed = S::parse(&input).expect("Input should parse");
+
+    let mut group = c.benchmark_group(day_name(year, day));
+    group.sample_size(10);
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&input))));
+    group.bench_function("part_1", |b| b.iter(|| S::part1(black_box(&parsed))));
+    group.bench_function("part_2", |b| b.iter(|| S::part2(black_box(&parsed))));
+    group.finish();
+}
+
+// Days written as a `Solution`. `new-day` appends to this list.
+fn bench_solutions(c: &mut Criterion) {
+    bench_phases::<day_14_lib::Day14>(c, 2023, 14);
+    bench_phases::<day_16_lib::Day16>(c, 2023, 16);
+}
+
+criterion_group!(benches, bench_solutions, bench_totals);
+criterion_main!(benches);
@@ -0,0 +1,82 @@
+use std::io::Write;
+
+use aoc_core::{registered_days, DEFAULT_YEAR};
+use clap::{builder::PossibleValuesParser, Arg, Command, CommandFactory};
+use clap_complete::{generate, Shell};
+
+use crate::Cli;
+
+// Writes a completion script for the shell. Running and watching only offer
+// the days that are linked into the runner, while new-day offers the rest.
+// The script is generated once, so the days are those of the default year.
+pub fn write_completions(shell: Shell, out: &mut dyn Write) {
+    let mut command = completion_command();
+    let name = command.get_name().to_owned();
+    generate(shell, &mut command, name, out);
+}
+
+fn completion_command() -> Command {
+    let solved = registered_days(DEFAULT_YEAR);
+    let unsolved: Vec<_> = (1..=25).filter(|day| !solved.contains(day)).collect();
+    Cli::command()
+        .mut_arg("day", |arg| offer_days(arg, &solved))
+        .mut_subcommand("watch", |watch| {
+            watch.mut_arg("day", |arg| offer_days(arg, &solved))
+        })
+        .mut_subcommand("new-day", |new_day| {
+            new_day.mut_arg("day", |arg| offer_days(arg, &unsolved))
+        })
+}
+
+// clap only takes static strings for possible values
+const DAY_NAMES: [&str; 25] = [
+    "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16", "17",
+    "18", "19", "20", "21", "22", "23", "24", "25",
+];
+
+// Only used to build the script, the real parser still accepts any day
+fn offer_days(arg: Arg, days: &[u8]) -> Arg {
+    let names = days.iter().map(|&day| DAY_NAMES[usize::from(day) - 1]);
+    arg.value_parser(PossibleValuesParser::new(names))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn offered_days(command: &Command) -> Vec<String> {
+        let day = command
+            .get_arguments()
+            .find(|arg| arg.get_id() == "day")
+            .unwrap();
+        day.get_possible_values()
+            .iter()
+            .map(|value| value.get_name().to_owned())
+            .collect()
+    }
+
+    #[test]
+    fn test_completion_days() {
+        let command = completion_command();
+        let solved = offered_days(&command);
+        assert!(solved.contains(&"16".to_owned()));
+        assert!(!solved.contains(&"18".to_owned()));
+
+        let watch = command.find_subcommand("watch").unwrap();
+        assert_eq!(offered_days(watch), solved);
+
+        let new_day = command.find_subcommand("new-day").unwrap();
+        let unsolved = offered_days(new_day);
+        assert!(unsolved.contains(&"18".to_owned()));
+        assert!(!unsolved.contains(&"16".to_owned()));
+    }
+
+    #[test]
+    fn test_write_completions() {
+        let mut script = Vec::new();
+        write_completions(Shell::Bash, &mut script);
+        let script = String::from_utf8(script).unwrap();
+        assert!(script.contains("aoc2023"));
+        assert!(script.contains("new-day"));
+    }
+}
@@ -0,0 +1,124 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::paths::workspace_root;
+
+// Settings from aoc.toml in the workspace root, for anything that would
+// otherwise be a code edit. Every setting is optional and a command line flag
+// for the same thing wins. Relative paths are from the workspace root.
+//
+//     session_file = "../secrets/aoc_session"
+//     input_dir = "inputs"
+//     threads = 4
+//     target_ms = 1000
+//     color = false
+//     progress = false
+#[derive(Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    // File holding the session cookie, instead of .aoc_session
+    pub session_file: Option<PathBuf>,
+    // Where downloaded inputs are kept, one directory per year, instead of the
+    // cache in the home directory
+    pub input_dir: Option<PathBuf>,
+    // Size of the rayon pool, instead of one thread per core
+    pub threads: Option<usize>,
+    // Default budget for run-all, as --target-ms
+    pub target_ms: Option<u64>,
+    // Set to false to never colour output, as --no-color
+    pub color: Option<bool>,
+    // Set to false to never show progress bars, as --quiet
+    pub progress: Option<bool>,
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+impl Config {
+    // A missing file is the same as an empty one
+    pub fn load(path: &Path) -> Result<Config> {
+        if !path.exists() {
+            return Ok(Config::default());
+        }
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        toml::from_str(&contents).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    pub fn session_file(&self) -> PathBuf {
+        let file = self
+            .session_file
+            .as_deref()
+            .unwrap_or(Path::new(".aoc_session"));
+        workspace_root().join(file)
+    }
+
+    pub fn input_dir(&self, year: u16) -> Option<PathBuf> {
+        let dir = self.input_dir.as_ref()?;
+        Some(workspace_root().join(dir).join(year.to_string()))
+    }
+}
+
+// Only the first config installed is used
+pub fn set(config: Config) {
+    let _ = CONFIG.set(config);
+}
+
+// The installed config, or the defaults when there is none, as in tests
+pub fn get() -> &'static Config {
+    CONFIG.get_or_init(Config::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_config() {
+        let config: Config = toml::from_str(
+            "session_file = \"secrets/session\"\nthreads = 4\ntarget_ms = 1000\ncolor = false\n",
+        )
+        .unwrap();
+        assert_eq!(
+            config,
+            Config {
+                session_file: Some(PathBuf::from("secrets/session")),
+                threads: Some(4),
+                target_ms: Some(1000),
+                color: Some(false),
+                ..Config::default()
+            }
+        );
+        assert!(config.session_file().ends_with("secrets/session"));
+        assert_eq!(config.input_dir(2023), None);
+    }
+
+    #[test]
+    fn test_unknown_settings_are_an_error() {
+        assert!(toml::from_str::<Config>("theads = 4").is_err());
+        assert!(toml::from_str::<Config>("threads = \"four\"").is_err());
+    }
+
+    #[test]
+    fn test_missing_config_is_default() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = Config::load(&dir.path().join("aoc.toml")).unwrap();
+        assert_eq!(config, Config::default());
+        assert!(config.session_file().ends_with(".aoc_session"));
+    }
+
+    #[test]
+    fn test_input_dir_is_split_by_year() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("aoc.toml");
+        fs::write(&path, "input_dir = \"/srv/aoc\"\n").unwrap();
+
+        let config = Config::load(&path).unwrap();
+        assert_eq!(config.input_dir(2024), Some(PathBuf::from("/srv/aoc/2024")));
+    }
+}
@@ -0,0 +1,32 @@
+// Solutions register themselves, but each day crate still has to be linked in
+// for its registrations to be collected. `new-day` appends to this list.
+use day_01_part_1 as _;
+use day_01_part_2 as _;
+use day_02_part_1 as _;
+use day_02_part_2 as _;
+use day_03_part_1 as _;
+use day_03_part_2 as _;
+use day_04_part_1 as _;
+use day_04_part_2 as _;
+use day_05_part_1 as _;
+use day_05_part_2 as _;
+use day_06_part_1 as _;
+use day_07_part_1 as _;
+use day_07_part_2 as _;
+use day_08_part_1 as _;
+use day_08_part_2 as _;
+use day_09_part_1 as _;
+use day_09_part_2 as _;
+use day_10_part_1 as _;
+use day_10_part_2 as _;
+use day_11_part_1 as _;
+use day_11_part_2 as _;
+use day_12_part_1 as _;
+use day_13_part_1 as _;
+use day_13_part_2 as _;
+use day_14_lib as _;
+use day_15_part_1 as _;
+use day_15_part_2 as _;
+use day_16_lib as _;
+use day_17_part_1 as _;
+use day_17_part_2 as _;
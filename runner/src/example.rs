@@ -0,0 +1,63 @@
+use std::fs;
+
+use anyhow::{Context, Result};
+
+use crate::paths::example_dir;
+
+// The worked example from a day's puzzle description, kept next to the day so
+// a part can be checked without its real input
+pub struct Example {
+    pub input: String,
+    pub expected: String,
+}
+
+impl Example {
+    pub fn load(year: u16, day: u8, part: u8) -> Result<Example> {
+        let dir = example_dir(year, day);
+        let input_path = dir.join(format!("part_{part}.txt"));
+        let answer_path = dir.join(format!("part_{part}.answer"));
+
+        let input = fs::read_to_string(&input_path)
+            .with_context(|| format!("Failed to read {}", input_path.display()))?;
+        let expected = fs::read_to_string(&answer_path)
+            .with_context(|| format!("Failed to read {}", answer_path.display()))?;
+        Ok(Example {
+            input,
+            expected: expected.trim().to_owned(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{registrations, DEFAULT_YEAR};
+
+    use super::*;
+    use crate::solve;
+
+    #[test]
+    fn test_load_example() {
+        let example = Example::load(DEFAULT_YEAR, 6, 2).unwrap();
+        assert_eq!(
+            example.input,
+            "Time:      7  15   30\nDistance:  9  40  200\n"
+        );
+        assert_eq!(example.expected, "71503");
+    }
+
+    #[test]
+    fn test_load_missing_example() {
+        assert!(Example::load(DEFAULT_YEAR, 25, 1).is_err());
+    }
+
+    #[test]
+    fn test_every_registered_part_solves_its_example() {
+        for registration in registrations() {
+            let (year, day, part) = (registration.year, registration.day, registration.part);
+            let example = Example::load(year, day, part).unwrap();
+            let actual = solve(year, day, part, &example.input)
+                .unwrap_or_else(|error| panic!("day {day} part {part}: {error:#}"));
+            assert_eq!(actual.answer, example.expected, "day {day} part {part}");
+        }
+    }
+}
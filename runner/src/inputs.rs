@@ -0,0 +1,119 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+
+use crate::{config, paths::input_path, site};
+
+// Where to find a part's puzzle input. A puzzle_input.txt next to the part
+// wins, otherwise the input comes from the cache, which is filled by
+// downloading it once. Keeping downloads in the cache keeps them out of the
+// repo while fresh clones stay runnable.
+pub fn ensure_input(year: u16, day: u8, part: u8, offline: bool) -> Result<PathBuf> {
+    let local = input_path(year, day, part);
+    if local.exists() {
+        return Ok(local);
+    }
+    let cached = cache_dir(year)?.join(format!("day_{day:02}.txt"));
+    fill_cache(&cached, offline, || download(year, day))
+        .with_context(|| format!("No input for day {day} part {part}"))?;
+    Ok(cached)
+}
+
+fn fill_cache(path: &Path, offline: bool, fetch: impl FnOnce() -> Result<String>) -> Result<()> {
+    if path.exists() {
+        return Ok(());
+    }
+    if offline {
+        bail!(
+            "{} is not cached and --offline forbids downloading it",
+            path.display()
+        );
+    }
+    let input = fetch()?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    // Written beside the cache file and renamed into place, so nothing ever
+    // reads a half written input
+    let partial = path.with_extension(format!("{}.tmp", std::process::id()));
+    fs::write(&partial, input).with_context(|| format!("Failed to write {}", partial.display()))?;
+    fs::rename(&partial, path)
+        .with_context(|| format!("Failed to move the input to {}", path.display()))?;
+    eprintln!("Downloaded {}", path.display());
+    Ok(())
+}
+
+// ~/.cache/aoc2023 for 2023, or under XDG_CACHE_HOME when that is set. The
+// input_dir in aoc.toml replaces it.
+fn cache_dir(year: u16) -> Result<PathBuf> {
+    if let Some(dir) = config::get().input_dir(year) {
+        return Ok(dir);
+    }
+    let base = match env::var_os("XDG_CACHE_HOME") {
+        Some(cache) => PathBuf::from(cache),
+        None => PathBuf::from(env::var_os("HOME").context("HOME is not set")?).join(".cache"),
+    };
+    Ok(base.join(format!("aoc{year}")))
+}
+
+pub fn download(year: u16, day: u8) -> Result<String> {
+    site::agent()
+        .get(&format!("{}/input", site::day_url(year, day)))
+        .set("Cookie", &format!("session={}", site::session()?))
+        .call()
+        .with_context(|| format!("Failed to download the input for day {day}"))?
+        .into_string()
+        .context("The downloaded input was not valid text")
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+
+    use super::*;
+
+    #[test]
+    fn test_cached_input_is_not_downloaded() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("day_16.txt");
+        fs::write(&path, "mine").unwrap();
+
+        fill_cache(&path, false, || panic!("Should not download")).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "mine");
+    }
+
+    #[test]
+    fn test_missing_input_is_downloaded_into_the_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("aoc2023/day_16.txt");
+
+        fill_cache(&path, false, || Ok("downloaded\n".to_owned())).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "downloaded\n");
+        // Only the input itself is left behind
+        let files = fs::read_dir(path.parent().unwrap()).unwrap().count();
+        assert_eq!(files, 1);
+    }
+
+    #[test]
+    fn test_failed_download_writes_nothing() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("day_16.txt");
+
+        assert!(fill_cache(&path, false, || Err(anyhow!("no session"))).is_err());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_offline_never_downloads() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("day_16.txt");
+
+        assert!(fill_cache(&path, true, || panic!("Should not download")).is_err());
+
+        fs::write(&path, "cached").unwrap();
+        assert!(fill_cache(&path, true, || panic!("Should not download")).is_ok());
+    }
+}
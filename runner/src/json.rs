@@ -0,0 +1,108 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::run_all::PartResult;
+
+// One line of machine readable output. A part that failed has no answer or
+// timings and carries the error instead.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct JsonResult {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub parse_ms: Option<f64>,
+    pub solve_ms: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl From<&PartResult> for JsonResult {
+    fn from(result: &PartResult) -> Self {
+        let (answer, parse_ms, solve_ms, error) = match &result.outcome {
+            Ok(timed) => (
+                Some(timed.answer.clone()),
+                timed.parse.map(millis),
+                Some(millis(timed.solve)),
+                None,
+            ),
+            Err(error) => (None, None, None, Some(format!("{error:#}"))),
+        };
+        JsonResult {
+            day: result.day,
+            part: result.part,
+            answer,
+            parse_ms,
+            solve_ms,
+            error,
+        }
+    }
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+pub fn to_json(result: &PartResult) -> String {
+    serde_json::to_string(&JsonResult::from(result)).expect("Results always serialize")
+}
+
+pub fn to_json_array(results: &[PartResult]) -> String {
+    let results: Vec<_> = results.iter().map(JsonResult::from).collect();
+    serde_json::to_string(&results).expect("Results always serialize")
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+    use aoc_core::TimedAnswer;
+
+    use super::*;
+
+    #[test]
+    fn test_to_json() {
+        let result = PartResult {
+            day: 16,
+            part: 1,
+            outcome: Ok(TimedAnswer {
+                answer: "46".to_owned(),
+                parse: Some(Duration::from_micros(250)),
+                solve: Duration::from_micros(1500),
+            }),
+        };
+        assert_eq!(
+            to_json(&result),
+            r#"{"day":16,"part":1,"answer":"46","parse_ms":0.25,"solve_ms":1.5}"#
+        );
+    }
+
+    #[test]
+    fn test_to_json_without_parse_time() {
+        let result = PartResult {
+            day: 1,
+            part: 2,
+            outcome: Ok(TimedAnswer {
+                answer: "281".to_owned(),
+                parse: None,
+                solve: Duration::from_millis(2),
+            }),
+        };
+        assert_eq!(
+            to_json(&result),
+            r#"{"day":1,"part":2,"answer":"281","parse_ms":null,"solve_ms":2.0}"#
+        );
+    }
+
+    #[test]
+    fn test_to_json_array_with_error() {
+        let results = [PartResult {
+            day: 17,
+            part: 1,
+            outcome: Err(anyhow!("missing input")),
+        }];
+        assert_eq!(
+            to_json_array(&results),
+            r#"[{"day":17,"part":1,"answer":null,"parse_ms":null,"solve_ms":null,"error":"missing input"}]"#
+        );
+    }
+}
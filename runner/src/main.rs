@@ -0,0 +1,676 @@
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use anyhow::{bail, Context, Result};
+use aoc_core::{
+    find_registration, registrations_for, InputSource, Registration, TimedAnswer, DEFAULT_YEAR,
+};
+use clap::{Parser, Subcommand};
+use config::Config;
+use example::Example;
+use paths::{workspace_root, year_dir};
+use run_all::PartResult;
+use stats::TimingStats;
+use style::Style;
+
+mod completions;
+mod config;
+mod days;
+mod example;
+mod inputs;
+mod json;
+mod paths;
+mod profile;
+mod progress;
+mod run_all;
+mod scaffold;
+mod site;
+mod stats;
+mod style;
+mod submit;
+mod verify;
+mod watch;
+
+/// Run a single Advent of Code solution against its puzzle input
+#[derive(Parser, Debug)]
+#[command(name = "aoc2023", subcommand_negates_reqs = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// Year of the puzzles, for everything from running a part to new-day
+    #[arg(short, long, global = true, default_value_t = DEFAULT_YEAR, value_parser = clap::value_parser!(u16).range(2015..))]
+    year: u16,
+    /// Day of the puzzle to run
+    #[arg(short, long, required = true, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+    /// Part of the puzzle to run
+    #[arg(short, long, required = true, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Read the puzzle input from this file instead of the day's puzzle_input.txt
+    #[arg(short, long, value_name = "PATH")]
+    input: Option<InputSource>,
+    /// Where to read the puzzle input, `-` reads it from stdin
+    #[arg(value_name = "INPUT", conflicts_with = "input")]
+    source: Option<InputSource>,
+    /// Solve the day's bundled example and compare it with the expected answer
+    #[arg(short, long, conflicts_with_all = ["input", "source"])]
+    example: bool,
+    /// Time this many runs after a warmup and report min, median and stddev
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..), conflicts_with_all = ["example", "json"])]
+    repeat: Option<u32>,
+    /// Only parse the input and describe what was parsed, without solving
+    #[arg(long, conflicts_with_all = ["repeat", "submit", "json"])]
+    parse_only: bool,
+    /// Write a flamegraph of the solve to target/flamegraphs
+    #[arg(long, conflicts_with_all = ["example", "repeat", "parse_only"])]
+    profile: bool,
+    /// Submit the answer to adventofcode.com once it is solved
+    #[arg(long, conflicts_with_all = ["example", "repeat", "offline"])]
+    submit: bool,
+    /// Never touch the network, inputs must already be local or cached
+    #[arg(long, global = true)]
+    offline: bool,
+    /// Do not show progress bars for long running parts
+    #[arg(short, long, global = true)]
+    quiet: bool,
+    /// Never colour the output, which is also the case when it is not a terminal
+    #[arg(long, global = true)]
+    no_color: bool,
+    /// Threads for the solvers and run-all --parallel, one per core by default
+    #[arg(short = 'j', long, global = true, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+    threads: Option<usize>,
+    /// Print results as JSON instead of text
+    #[arg(long, global = true)]
+    json: bool,
+    /// Log what the solvers are doing to stderr, `-vv` for every step
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// List every day and part the runner can solve
+    List,
+    /// Run every solved part and print a table of answers and timings
+    RunAll {
+        /// Report whether the whole run fits in this many milliseconds
+        #[arg(long)]
+        target_ms: Option<u64>,
+        /// Solve the parts at the same time across all cores
+        #[arg(long)]
+        parallel: bool,
+    },
+    /// Check every solved part against the stored answers
+    Verify {
+        /// File of known answers, one `day part answer` per line. Defaults to
+        /// answers.txt in the year's directory
+        #[arg(long, value_name = "PATH")]
+        answers: Option<PathBuf>,
+    },
+    /// Re-run a part whenever its day's source or input changes
+    Watch {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+    },
+    /// Create the crates for a new day and register it with the runner
+    NewDay {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Directory of templates to use instead of the built-in ones, any of
+        /// lib.rs.tmpl, tests.rs.tmpl, main.rs.tmpl, lib_Cargo.toml.tmpl and
+        /// part_Cargo.toml.tmpl
+        #[arg(long, value_name = "DIR")]
+        templates: Option<PathBuf>,
+    },
+    /// Print a completion script for the shell, e.g.
+    /// `aoc2023 completions bash > ~/.local/share/bash-completion/completions/aoc2023`
+    Completions { shell: clap_complete::Shell },
+}
+
+// run-all and verify exit with a failure when any part errors or does not match
+// its answer, listing each on stderr, so they can gate scripts and git hooks
+fn main() -> Result<ExitCode> {
+    let cli = Cli::parse();
+    init_logging(cli.verbose);
+    let config = Config::load(&workspace_root().join("aoc.toml"))?;
+    init_threads(cli.threads.or(config.threads))?;
+    if !cli.quiet && config.progress != Some(false) {
+        aoc_core::set_progress_sink(Box::new(progress::IndicatifSink::new()));
+    }
+    let style = Style::for_stdout(cli.no_color || config.color == Some(false));
+    config::set(config);
+    match (cli.command, cli.day, cli.part) {
+        (Some(Command::List), _, _) => list(cli.year),
+        (
+            Some(Command::RunAll {
+                target_ms,
+                parallel,
+            }),
+            _,
+            _,
+        ) => {
+            let start = Instant::now();
+            let results = run_all::run_all(cli.year, parallel, cli.offline);
+            let wall = start.elapsed();
+            let target = target_ms
+                .or(config::get().target_ms)
+                .map(Duration::from_millis);
+            if cli.json {
+                println!("{}", json::to_json_array(&results));
+            } else {
+                print!("{}", run_all::format_table(&results, target, style));
+            }
+            if parallel && !cli.json {
+                println!("Wall time {}", run_all::format_duration(wall));
+            }
+            return Ok(report_failures(&run_all::format_failures(&results)));
+        }
+        (Some(Command::Verify { answers }), _, _) => {
+            let path = answers.unwrap_or_else(|| year_dir(cli.year).join("answers.txt"));
+            let answers = verify::read_answers(&path)?;
+            let results = run_all::run_all(cli.year, false, cli.offline);
+            let verdicts = verify::verify(&results, &answers);
+            print!("{}", verify::format_verdicts(&results, &verdicts, style));
+            return Ok(report_failures(&verify::format_failures(
+                &results, &verdicts,
+            )));
+        }
+        (Some(Command::Watch { day, part }), _, _) => {
+            watch::watch(&workspace_root(), cli.year, day, part, cli.threads)?
+        }
+        (Some(Command::NewDay { day, templates }), _, _) => {
+            let root = workspace_root();
+            scaffold::new_day(&root, cli.year, day, templates.as_deref())?;
+            let day_dir = paths::day_dir(cli.year, day);
+            let day_dir = day_dir.strip_prefix(&root).unwrap_or(&day_dir);
+            println!(
+                "Created {}, add the examples to examples/",
+                day_dir.display()
+            );
+        }
+        (Some(Command::Completions { shell }), _, _) => {
+            completions::write_completions(shell, &mut std::io::stdout());
+        }
+        (None, Some(day), Some(part)) if cli.parse_only => {
+            // Checked first so an unsolved part never downloads its input
+            registration(cli.year, day, part)?;
+            let input = if cli.example {
+                Example::load(cli.year, day, part)?.input
+            } else {
+                let source = cli.input.or(cli.source);
+                resolve_source(source, cli.year, day, part, cli.offline)?.read()?
+            };
+            let summary = parse_only(cli.year, day, part, &input)?;
+            println!("Day {day:02} Part {part}: {summary}");
+        }
+        (None, Some(day), Some(part)) if cli.example => run_example(cli.year, day, part)?,
+        (None, Some(day), Some(part)) => {
+            registration(cli.year, day, part)?;
+            let source =
+                resolve_source(cli.input.or(cli.source), cli.year, day, part, cli.offline)?;
+            let profile = cli
+                .profile
+                .then(|| profile::flamegraph_path(cli.year, day, part));
+            let answer = run(
+                cli.year,
+                day,
+                part,
+                &source,
+                cli.json,
+                cli.repeat,
+                profile.as_deref(),
+            )?;
+            if cli.submit {
+                let history = year_dir(cli.year).join("submissions.jsonl");
+                let submission = submit::submit(&history, cli.year, day, part, &answer)?;
+                println!("{}", submission.message);
+            }
+        }
+        _ => unreachable!("clap requires a day and part when there is no subcommand"),
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn report_failures(failures: &str) -> ExitCode {
+    if failures.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        eprint!("{failures}");
+        ExitCode::FAILURE
+    }
+}
+
+// Falls back to the day's own input, downloading it if needed
+fn resolve_source(
+    source: Option<InputSource>,
+    year: u16,
+    day: u8,
+    part: u8,
+    offline: bool,
+) -> Result<InputSource> {
+    match source {
+        Some(source) => Ok(source),
+        None => Ok(InputSource::File(inputs::ensure_input(
+            year, day, part, offline,
+        )?)),
+    }
+}
+
+// Every solver uses rayon's global pool, so sizing it here covers them all,
+// including the parts run side by side by run-all --parallel
+fn init_threads(threads: Option<usize>) -> Result<()> {
+    if let Some(threads) = threads {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global()
+            .context("Failed to start the thread pool")?;
+        tracing::debug!(threads, "sized the thread pool");
+    }
+    Ok(())
+}
+
+fn init_logging(verbose: u8) {
+    let level = match verbose {
+        0 => return,
+        1 => tracing::Level::DEBUG,
+        _ => tracing::Level::TRACE,
+    };
+    tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_writer(std::io::stderr)
+        .init();
+}
+
+fn list(year: u16) {
+    let registrations = registrations_for(year);
+    for day in 1..=25 {
+        let parts: Vec<_> = registrations
+            .iter()
+            .filter(|r| r.day == day)
+            .map(|r| r.part.to_string())
+            .collect();
+        if !parts.is_empty() {
+            println!("Day {day:02}: part {}", parts.join(", "));
+        }
+    }
+}
+
+// Returns the answer so it can be submitted
+fn run(
+    year: u16,
+    day: u8,
+    part: u8,
+    source: &InputSource,
+    as_json: bool,
+    repeat: Option<u32>,
+    profile: Option<&Path>,
+) -> Result<String> {
+    let input = source.read()?;
+    let timed = match profile {
+        Some(path) => {
+            let timed = profile::flamegraph(path, || solve(year, day, part, &input))?;
+            eprintln!("Wrote a flamegraph to {}", path.display());
+            timed
+        }
+        None => solve(year, day, part, &input)?,
+    };
+    let answer = timed.answer.clone();
+    if let Some(runs) = repeat {
+        // The run above doubles as the warmup
+        let samples = (0..runs)
+            .map(|_| solve(year, day, part, &input).map(|timed| timed.total()))
+            .collect::<Result<Vec<_>>>()?;
+        let stats = TimingStats::from_samples(&samples).context("No runs were timed")?;
+        println!("Day {:02} Part {}: {}", day, part, timed.answer);
+        println!("{stats}");
+    } else if as_json {
+        let result = PartResult {
+            day,
+            part,
+            outcome: Ok(timed),
+        };
+        println!("{}", json::to_json(&result));
+    } else {
+        println!("Day {:02} Part {}: {}", day, part, timed.answer);
+        println!("{}", format_timing(&timed));
+    }
+    Ok(answer)
+}
+
+fn format_timing(timed: &TimedAnswer) -> String {
+    let solve = run_all::format_duration(timed.solve);
+    match timed.parse {
+        Some(parse) => format!("parse {}, solve {solve}", run_all::format_duration(parse)),
+        // The part parses its own input, so this covers both phases
+        None => format!("parse and solve {solve}"),
+    }
+}
+
+fn run_example(year: u16, day: u8, part: u8) -> Result<()> {
+    let example = Example::load(year, day, part)?;
+    let timed = solve(year, day, part, &example.input)?;
+    println!("Day {:02} Part {} example: {}", day, part, timed.answer);
+    println!("Expected: {}", example.expected);
+    if timed.answer != example.expected {
+        bail!("Day {day} part {part} does not match its example");
+    }
+    Ok(())
+}
+
+fn registration(year: u16, day: u8, part: u8) -> Result<&'static Registration> {
+    find_registration(year, day, part)
+        .with_context(|| format!("{year} day {day} part {part} has not been solved yet"))
+}
+
+fn solve(year: u16, day: u8, part: u8, input: &str) -> Result<TimedAnswer> {
+    let registration = registration(year, day, part)?;
+    let _span = tracing::info_span!("solve", year, day, part).entered();
+    (registration.solve)(input)
+}
+
+fn parse_only(year: u16, day: u8, part: u8, input: &str) -> Result<String> {
+    let registration = registration(year, day, part)?;
+    let parse = registration.parse.with_context(|| {
+        format!("Day {day} part {part} parses as it solves, so it cannot only parse")
+    })?;
+    let _span = tracing::info_span!("parse_only", year, day, part).entered();
+    parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_dispatches_to_the_selected_part() {
+        let input = "Time:      7  15   30\nDistance:  9  40  200";
+
+        assert_eq!(solve(DEFAULT_YEAR, 6, 1, input).unwrap().answer, "288");
+        assert_eq!(solve(DEFAULT_YEAR, 6, 2, input).unwrap().answer, "71503");
+    }
+
+    #[test]
+    fn test_parse_only() {
+        let input = "O.#\n.O.\n#..";
+        assert_eq!(
+            parse_only(DEFAULT_YEAR, 14, 2, input).unwrap(),
+            "3 columns of 3, 2 round rocks, 2 cube rocks"
+        );
+
+        assert!(parse_only(DEFAULT_YEAR, 6, 1, "").is_err());
+        assert!(parse_only(DEFAULT_YEAR, 25, 1, "").is_err());
+    }
+
+    #[test]
+    fn test_format_timing() {
+        let timed = TimedAnswer {
+            answer: "46".to_owned(),
+            parse: Some(Duration::from_micros(250)),
+            solve: Duration::from_micros(1500),
+        };
+        assert_eq!(format_timing(&timed), "parse 0.250 ms, solve 1.500 ms");
+
+        let timed = TimedAnswer {
+            parse: None,
+            ..timed
+        };
+        assert_eq!(format_timing(&timed), "parse and solve 1.500 ms");
+    }
+
+    #[test]
+    fn test_solve_unsolved_part_is_an_error() {
+        assert!(solve(DEFAULT_YEAR, 12, 2, "").is_err());
+        assert!(solve(DEFAULT_YEAR, 25, 1, "").is_err());
+    }
+
+    #[test]
+    fn test_input_path() {
+        let path = paths::input_path(DEFAULT_YEAR, 16, 2);
+        assert!(path.ends_with("day_16/part_2/puzzle_input.txt"));
+
+        let path = paths::input_path(DEFAULT_YEAR, 1, 1);
+        assert!(path.ends_with("day_01/part_1/data/puzzle_input.txt"));
+    }
+
+    #[test]
+    fn test_later_years_have_their_own_directory() {
+        assert_eq!(year_dir(DEFAULT_YEAR), workspace_root());
+
+        let path = paths::input_path(2024, 1, 1);
+        assert!(path.ends_with("2024/day_01/part_1/puzzle_input.txt"));
+        assert!(paths::example_dir(2024, 6).ends_with("2024/day_06/examples"));
+    }
+
+    #[test]
+    fn test_example_dir() {
+        assert!(paths::example_dir(DEFAULT_YEAR, 6).ends_with("day_06/examples"));
+    }
+
+    #[test]
+    fn test_every_linked_day_is_registered() {
+        let parts: Vec<_> = registrations_for(DEFAULT_YEAR)
+            .iter()
+            .map(|r| (r.day, r.part))
+            .collect();
+
+        assert_eq!(parts.len(), 33);
+        assert!(parts.contains(&(14, 1)));
+        assert!(parts.contains(&(16, 2)));
+        assert!(!parts.contains(&(12, 2)));
+    }
+
+    #[test]
+    fn test_args_reject_out_of_range_day() {
+        assert!(Cli::try_parse_from(["aoc2023", "--day", "16", "--part", "1"]).is_ok());
+        assert!(Cli::try_parse_from(["aoc2023", "--day", "26", "--part", "1"]).is_err());
+        assert!(Cli::try_parse_from(["aoc2023", "--day", "16", "--part", "3"]).is_err());
+    }
+
+    #[test]
+    fn test_args_input_override() {
+        let cli = Cli::try_parse_from(["aoc2023", "-d", "16", "-p", "1", "--input", "example.txt"])
+            .unwrap();
+        assert_eq!(
+            cli.input,
+            Some(InputSource::File(PathBuf::from("example.txt")))
+        );
+
+        let cli = Cli::try_parse_from(["aoc2023", "-d", "16", "-p", "1"]).unwrap();
+        assert_eq!(cli.input, None);
+    }
+
+    #[test]
+    fn test_args_stdin_source() {
+        let cli = Cli::try_parse_from(["aoc2023", "--day", "14", "--part", "2", "-"]).unwrap();
+        assert_eq!(cli.source, Some(InputSource::Stdin));
+
+        let cli = Cli::try_parse_from(["aoc2023", "-d", "14", "-p", "2", "--input", "-"]).unwrap();
+        assert_eq!(cli.input, Some(InputSource::Stdin));
+
+        // Only one of the two ways to give the input
+        assert!(
+            Cli::try_parse_from(["aoc2023", "-d", "14", "-p", "2", "-i", "a.txt", "-"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_args_example() {
+        let cli = Cli::try_parse_from(["aoc2023", "-d", "16", "-p", "1", "--example"]).unwrap();
+        assert!(cli.example);
+
+        // The example replaces the input, so both cannot be given
+        assert!(Cli::try_parse_from(["aoc2023", "-d", "16", "-p", "1", "-e", "-"]).is_err());
+        assert!(
+            Cli::try_parse_from(["aoc2023", "-d", "16", "-p", "1", "-e", "-i", "a.txt"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_args_verbose() {
+        let cli = Cli::try_parse_from(["aoc2023", "-d", "14", "-p", "2"]).unwrap();
+        assert_eq!(cli.verbose, 0);
+
+        let cli = Cli::try_parse_from(["aoc2023", "-d", "14", "-p", "2", "-vv"]).unwrap();
+        assert_eq!(cli.verbose, 2);
+
+        let cli = Cli::try_parse_from(["aoc2023", "run-all", "--verbose"]).unwrap();
+        assert_eq!(cli.verbose, 1);
+    }
+
+    #[test]
+    fn test_args_json() {
+        let cli = Cli::try_parse_from(["aoc2023", "-d", "16", "-p", "1", "--json"]).unwrap();
+        assert!(cli.json);
+
+        let cli = Cli::try_parse_from(["aoc2023", "run-all", "--json"]).unwrap();
+        assert!(cli.json);
+    }
+
+    #[test]
+    fn test_args_repeat() {
+        let cli =
+            Cli::try_parse_from(["aoc2023", "-d", "16", "-p", "2", "--repeat", "20"]).unwrap();
+        assert_eq!(cli.repeat, Some(20));
+
+        assert!(Cli::try_parse_from(["aoc2023", "-d", "16", "-p", "2", "--repeat", "0"]).is_err());
+        assert!(
+            Cli::try_parse_from(["aoc2023", "-d", "16", "-p", "2", "--repeat", "5", "--json"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_args_submit() {
+        let cli = Cli::try_parse_from(["aoc2023", "-d", "16", "-p", "2", "--submit"]).unwrap();
+        assert!(cli.submit);
+
+        // Example answers are never worth submitting
+        assert!(Cli::try_parse_from(["aoc2023", "-d", "16", "-p", "2", "--submit", "-e"]).is_err());
+    }
+
+    #[test]
+    fn test_args_offline() {
+        let cli = Cli::try_parse_from(["aoc2023", "run-all", "--offline"]).unwrap();
+        assert!(cli.offline);
+
+        assert!(
+            Cli::try_parse_from(["aoc2023", "-d", "16", "-p", "2", "--offline", "--submit"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_args_parse_only() {
+        let cli = Cli::try_parse_from(["aoc2023", "-d", "16", "-p", "1", "--parse-only"]).unwrap();
+        assert!(cli.parse_only);
+
+        assert!(
+            Cli::try_parse_from(["aoc2023", "-d", "16", "-p", "1", "--parse-only", "-e"]).is_ok()
+        );
+        assert!(Cli::try_parse_from([
+            "aoc2023",
+            "-d",
+            "16",
+            "-p",
+            "1",
+            "--parse-only",
+            "--submit"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_args_year() {
+        let cli = Cli::try_parse_from(["aoc2023", "-d", "1", "-p", "1"]).unwrap();
+        assert_eq!(cli.year, DEFAULT_YEAR);
+
+        let cli = Cli::try_parse_from(["aoc2023", "-y", "2024", "-d", "1", "-p", "1"]).unwrap();
+        assert_eq!(cli.year, 2024);
+
+        let cli = Cli::try_parse_from(["aoc2023", "new-day", "1", "--year", "2024"]).unwrap();
+        assert_eq!(cli.year, 2024);
+
+        // There was no Advent of Code before 2015
+        assert!(Cli::try_parse_from(["aoc2023", "run-all", "--year", "2014"]).is_err());
+    }
+
+    #[test]
+    fn test_solve_other_year_is_an_error() {
+        assert!(solve(2024, 14, 2, "").is_err());
+    }
+
+    #[test]
+    fn test_args_no_color() {
+        let cli = Cli::try_parse_from(["aoc2023", "verify", "--no-color"]).unwrap();
+        assert!(cli.no_color);
+
+        let cli = Cli::try_parse_from(["aoc2023", "run-all"]).unwrap();
+        assert!(!cli.no_color);
+    }
+
+    #[test]
+    fn test_args_profile() {
+        let cli = Cli::try_parse_from(["aoc2023", "-d", "14", "-p", "2", "--profile"]).unwrap();
+        assert!(cli.profile);
+
+        assert!(Cli::try_parse_from([
+            "aoc2023",
+            "-d",
+            "14",
+            "-p",
+            "2",
+            "--profile",
+            "--repeat",
+            "3"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_flamegraph_path() {
+        let path = profile::flamegraph_path(2023, 14, 2);
+        assert!(path.ends_with("target/flamegraphs/2023_day_14_part_2.svg"));
+    }
+
+    #[test]
+    fn test_args_threads() {
+        let cli = Cli::try_parse_from(["aoc2023", "run-all", "--parallel", "-j", "4"]).unwrap();
+        assert_eq!(cli.threads, Some(4));
+
+        let cli = Cli::try_parse_from(["aoc2023", "-d", "14", "-p", "2"]).unwrap();
+        assert_eq!(cli.threads, None);
+
+        assert!(Cli::try_parse_from(["aoc2023", "run-all", "--threads", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_args_quiet() {
+        let cli = Cli::try_parse_from(["aoc2023", "-d", "14", "-p", "2", "-q"]).unwrap();
+        assert!(cli.quiet);
+
+        let cli = Cli::try_parse_from(["aoc2023", "run-all", "--quiet"]).unwrap();
+        assert!(cli.quiet);
+    }
+
+    #[test]
+    fn test_args_day_and_part_only_required_without_subcommand() {
+        assert!(Cli::try_parse_from(["aoc2023"]).is_err());
+        assert!(Cli::try_parse_from(["aoc2023", "--day", "16"]).is_err());
+        assert!(Cli::try_parse_from(["aoc2023", "list"]).is_ok());
+        assert!(Cli::try_parse_from(["aoc2023", "run-all", "--target-ms", "1000"]).is_ok());
+        assert!(Cli::try_parse_from(["aoc2023", "run-all", "--parallel"]).is_ok());
+        assert!(Cli::try_parse_from(["aoc2023", "new-day", "18"]).is_ok());
+        assert!(Cli::try_parse_from(["aoc2023", "verify", "--answers", "a.txt"]).is_ok());
+        assert!(Cli::try_parse_from(["aoc2023", "watch", "--day", "16", "--part", "2"]).is_ok());
+        assert!(Cli::try_parse_from(["aoc2023", "watch", "--day", "16"]).is_err());
+        assert!(Cli::try_parse_from(["aoc2023", "new-day", "26"]).is_err());
+        assert!(Cli::try_parse_from(["aoc2023", "new-day", "18", "--templates", "mine"]).is_ok());
+        assert!(Cli::try_parse_from(["aoc2023", "completions", "zsh"]).is_ok());
+        assert!(Cli::try_parse_from(["aoc2023", "completions", "tcsh"]).is_err());
+    }
+}
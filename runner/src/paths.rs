@@ -0,0 +1,43 @@
+use std::path::PathBuf;
+
+use aoc_core::DEFAULT_YEAR;
+
+// Also compiled into the benchmarks, so this only depends on std and aoc_core
+// and has its tests in main.rs
+
+pub fn workspace_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+// The default year's days sit at the top of the workspace, later years each
+// get a directory of their own
+pub fn year_dir(year: u16) -> PathBuf {
+    if year == DEFAULT_YEAR {
+        workspace_root()
+    } else {
+        workspace_root().join(year.to_string())
+    }
+}
+
+pub fn day_dir(year: u16, day: u8) -> PathBuf {
+    year_dir(year).join(format!("day_{day:02}"))
+}
+
+// Inputs still live next to the part that was written to solve them, so
+// running a part from its own directory keeps working.
+pub fn input_path(year: u16, day: u8, part: u8) -> PathBuf {
+    let quirks = year == DEFAULT_YEAR;
+    // Day 6 part 2 is solved by the part 1 crate
+    let part = if quirks && day == 6 { 1 } else { part };
+    let part_dir = day_dir(year, day).join(format!("part_{part}"));
+    if quirks && day == 1 {
+        part_dir.join("data").join("puzzle_input.txt")
+    } else {
+        part_dir.join("puzzle_input.txt")
+    }
+}
+
+// The worked examples from the puzzle descriptions, with their answers
+pub fn example_dir(year: u16, day: u8) -> PathBuf {
+    day_dir(year, day).join("examples")
+}
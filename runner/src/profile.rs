@@ -0,0 +1,49 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+
+use crate::paths::workspace_root;
+
+// Kept under target so flamegraphs never end up in the repo
+pub fn flamegraph_path(year: u16, day: u8, part: u8) -> PathBuf {
+    workspace_root()
+        .join("target")
+        .join("flamegraphs")
+        .join(format!("{year}_day_{day:02}_part_{part}.svg"))
+}
+
+// Samples the stack while `run` runs and writes a flamegraph of it to `path`.
+// The sampling is too coarse to say much about parts that finish in a few
+// milliseconds.
+#[cfg(all(unix, feature = "profile"))]
+pub fn flamegraph<T>(path: &Path, run: impl FnOnce() -> Result<T>) -> Result<T> {
+    use std::fs::{self, File};
+
+    use anyhow::Context;
+
+    let guard = pprof::ProfilerGuardBuilder::default()
+        .frequency(997)
+        .blocklist(&["libc", "libgcc", "pthread", "vdso"])
+        .build()
+        .context("Failed to start the profiler")?;
+    let result = run()?;
+
+    let report = guard
+        .report()
+        .build()
+        .context("Failed to build the profile")?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let file =
+        File::create(path).with_context(|| format!("Failed to create {}", path.display()))?;
+    report
+        .flamegraph(file)
+        .context("Failed to draw the flamegraph, the run may have been too short to sample")?;
+    Ok(result)
+}
+
+#[cfg(not(all(unix, feature = "profile")))]
+pub fn flamegraph<T>(_path: &Path, _run: impl FnOnce() -> Result<T>) -> Result<T> {
+    anyhow::bail!("Profiling needs the profile feature on a Unix-like OS")
+}
@@ -0,0 +1,48 @@
+use aoc_core::{ProgressBar, ProgressSink};
+use indicatif::{MultiProgress, ProgressStyle};
+
+// Draws solver progress on stderr. Bars share one MultiProgress so parallel
+// runs of several days do not draw over each other.
+pub struct IndicatifSink {
+    bars: MultiProgress,
+}
+
+impl IndicatifSink {
+    pub fn new() -> Self {
+        IndicatifSink {
+            bars: MultiProgress::new(),
+        }
+    }
+}
+
+impl ProgressSink for IndicatifSink {
+    fn start(&self, label: &str, total: Option<u64>) -> Box<dyn ProgressBar> {
+        let bar = match total {
+            Some(total) => indicatif::ProgressBar::new(total).with_style(
+                ProgressStyle::with_template("{msg} [{bar:40}] {human_pos}/{human_len} {per_sec}")
+                    .expect("The template is valid")
+                    .progress_chars("=> "),
+            ),
+            None => indicatif::ProgressBar::new_spinner().with_style(
+                ProgressStyle::with_template("{spinner} {msg} {human_pos} {per_sec}")
+                    .expect("The template is valid"),
+            ),
+        };
+        let bar = self.bars.add(bar).with_message(label.to_owned());
+        Box::new(Bar(bar))
+    }
+}
+
+struct Bar(indicatif::ProgressBar);
+
+impl ProgressBar for Bar {
+    fn inc(&self, delta: u64) {
+        self.0.inc(delta);
+    }
+
+    // Loops can stop before their total, so the bar is cleared rather than
+    // left looking unfinished
+    fn finish(&self) {
+        self.0.finish_and_clear();
+    }
+}
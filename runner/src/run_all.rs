@@ -0,0 +1,248 @@
+use std::{collections::HashMap, fmt::Write, path::PathBuf, time::Duration};
+
+use anyhow::{bail, Result};
+use aoc_core::{registrations_for, InputSource, Registration, TimedAnswer};
+use rayon::prelude::*;
+
+use crate::{inputs::ensure_input, style::Style};
+
+pub struct PartResult {
+    pub day: u8,
+    pub part: u8,
+    pub outcome: Result<TimedAnswer>,
+}
+
+// Runs every registered part of the year, either one after another or spread across the
+// global rayon pool. Missing inputs are downloaded first unless offline, and an
+// input that still cannot be read or a failing part is recorded in its row rather than
+// stopping the whole run. Getting the input is not timed.
+//
+// The parallel run deliberately shares the global pool with the days that use
+// rayon themselves (14, 16 and others), so their inner work is stolen by idle
+// threads instead of every day spinning up a pool of its own. Each part's time
+// then includes waiting on others, so only the sequential run gives
+// comparable timings.
+pub fn run_all(year: u16, parallel: bool, offline: bool) -> Vec<PartResult> {
+    let registrations = registrations_for(year);
+    // Fetched one part at a time before anything runs, so the parts of a day
+    // share one download instead of racing to fetch it
+    let inputs = fetch_inputs(&registrations, offline);
+    if parallel {
+        registrations
+            .into_par_iter()
+            .zip(inputs)
+            .map(|(registration, input)| run_part(registration, input))
+            .collect()
+    } else {
+        registrations
+            .into_iter()
+            .zip(inputs)
+            .map(|(registration, input)| run_part(registration, input))
+            .collect()
+    }
+}
+
+// A day whose input could not be fetched isn't tried again for its other part
+fn fetch_inputs(registrations: &[&Registration], offline: bool) -> Vec<Result<PathBuf>> {
+    let mut failed: HashMap<u8, String> = HashMap::new();
+    registrations
+        .iter()
+        .map(|registration| {
+            let day = registration.day;
+            if let Some(error) = failed.get(&day) {
+                bail!("{error}");
+            }
+            ensure_input(registration.year, day, registration.part, offline).inspect_err(|error| {
+                failed.insert(day, format!("{error:#}"));
+            })
+        })
+        .collect()
+}
+
+fn run_part(registration: &Registration, input: Result<PathBuf>) -> PartResult {
+    let outcome = input
+        .and_then(|path| InputSource::File(path).read())
+        .and_then(|input| (registration.solve)(&input));
+    PartResult {
+        day: registration.day,
+        part: registration.part,
+        outcome,
+    }
+}
+
+// Parts that parse their own input have no parse time and show a dash, with
+// everything they did counted as solving. The slowest part's total is
+// highlighted when there is colour.
+pub fn format_table(results: &[PartResult], target: Option<Duration>, style: Style) -> String {
+    let answers: Vec<String> = results
+        .iter()
+        .map(|result| match &result.outcome {
+            Ok(timed) => timed.answer.clone(),
+            Err(error) => format!("error: {error}"),
+        })
+        .collect();
+    let width = answers.iter().map(String::len).max().unwrap_or(0).max(6);
+    let timings: Vec<_> = results
+        .iter()
+        .filter_map(|result| result.outcome.as_ref().ok())
+        .collect();
+    let parse_total: Duration = timings.iter().filter_map(|timed| timed.parse).sum();
+    let solve_total: Duration = timings.iter().map(|timed| timed.solve).sum();
+    let total = parse_total + solve_total;
+    let slowest = timings.iter().map(|timed| timed.total()).max();
+
+    let mut table = String::new();
+    writeln!(
+        table,
+        "Day  Part  {:<width$}  {:>12}  {:>12}  {:>12}",
+        "Answer", "Parse", "Solve", "Total"
+    )
+    .unwrap();
+    for (result, answer) in results.iter().zip(&answers) {
+        // Padded before painting, as colour codes would count towards the width
+        let answer = format!("{answer:<width$}");
+        let (answer, parse, solve, part_total) = match &result.outcome {
+            Ok(timed) => {
+                let part_total = format!("{:>12}", format_duration(timed.total()));
+                let part_total = if Some(timed.total()) == slowest {
+                    style.slowest(&part_total)
+                } else {
+                    part_total
+                };
+                (
+                    answer,
+                    timed.parse.map_or_else(|| "-".to_owned(), format_duration),
+                    format_duration(timed.solve),
+                    part_total,
+                )
+            }
+            Err(_) => (
+                style.fail(&answer),
+                "-".to_owned(),
+                "-".to_owned(),
+                format!("{:>12}", "-"),
+            ),
+        };
+        writeln!(
+            table,
+            "{:>3}  {:>4}  {answer}  {:>12}  {:>12}  {part_total}",
+            result.day, result.part, parse, solve
+        )
+        .unwrap();
+    }
+    writeln!(
+        table,
+        "{:<9}  {:<width$}  {:>12}  {:>12}  {:>12}",
+        "Total",
+        "",
+        format_duration(parse_total),
+        format_duration(solve_total),
+        format_duration(total)
+    )
+    .unwrap();
+    writeln!(table, "{}", style.stars(timings.len())).unwrap();
+
+    if let Some(target) = target {
+        let verdict = if total <= target {
+            style.pass("under")
+        } else {
+            style.fail("over")
+        };
+        writeln!(table, "{verdict} the {} target", format_duration(target)).unwrap();
+    }
+    table
+}
+
+// One line per part that errored, for stderr once the table is printed
+pub fn format_failures(results: &[PartResult]) -> String {
+    let mut failures = String::new();
+    for result in results {
+        if let Err(error) = &result.outcome {
+            writeln!(
+                failures,
+                "Day {:02} Part {} failed: {error:#}",
+                result.day, result.part
+            )
+            .unwrap();
+        }
+    }
+    failures
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    format!("{:.3} ms", duration.as_secs_f64() * 1000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+
+    fn results() -> Vec<PartResult> {
+        vec![
+            PartResult {
+                day: 1,
+                part: 1,
+                outcome: Ok(TimedAnswer {
+                    answer: "54331".to_owned(),
+                    parse: None,
+                    solve: Duration::from_micros(1500),
+                }),
+            },
+            PartResult {
+                day: 14,
+                part: 2,
+                outcome: Ok(TimedAnswer {
+                    answer: "64".to_owned(),
+                    parse: Some(Duration::from_micros(100)),
+                    solve: Duration::from_micros(400),
+                }),
+            },
+            PartResult {
+                day: 16,
+                part: 2,
+                outcome: Err(anyhow!("missing input")),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_format_table() {
+        let expected = "\
+Day  Part  Answer                       Parse         Solve         Total
+  1     1  54331                            -      1.500 ms      1.500 ms
+ 14     2  64                        0.100 ms      0.400 ms      0.500 ms
+ 16     2  error: missing input             -             -             -
+Total                                0.100 ms      1.900 ms      2.000 ms
+** 2 stars
+";
+        assert_eq!(format_table(&results(), None, Style::new(false)), expected);
+    }
+
+    #[test]
+    fn test_format_table_target() {
+        let table = format_table(&results(), Some(Duration::from_secs(1)), Style::new(false));
+        assert!(table.ends_with("under the 1000.000 ms target\n"));
+
+        let table = format_table(
+            &results(),
+            Some(Duration::from_millis(1)),
+            Style::new(false),
+        );
+        assert!(table.ends_with("over the 1.000 ms target\n"));
+    }
+
+    #[test]
+    fn test_format_failures() {
+        assert_eq!(
+            format_failures(&results()),
+            "Day 16 Part 2 failed: missing input\n"
+        );
+        assert_eq!(format_failures(&results()[..2]), "");
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(1_234_567)), "1.235 ms");
+    }
+}
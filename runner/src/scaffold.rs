@@ -0,0 +1,351 @@
+use std::{fs, path::Path};
+
+use anyhow::{bail, Context, Result};
+use aoc_core::DEFAULT_YEAR;
+use handlebars::Handlebars;
+use serde_json::json;
+
+// The built-in templates by name, along with the file a user template
+// overrides them from. `tests` is included into `lib` as a partial.
+const TEMPLATES: [(&str, &str, &str); 5] = [
+    (
+        "lib",
+        "lib.rs.tmpl",
+        include_str!("../templates/lib.rs.tmpl"),
+    ),
+    (
+        "tests",
+        "tests.rs.tmpl",
+        include_str!("../templates/tests.rs.tmpl"),
+    ),
+    (
+        "lib_manifest",
+        "lib_Cargo.toml.tmpl",
+        include_str!("../templates/lib_Cargo.toml.tmpl"),
+    ),
+    (
+        "main",
+        "main.rs.tmpl",
+        include_str!("../templates/main.rs.tmpl"),
+    ),
+    (
+        "part_manifest",
+        "part_Cargo.toml.tmpl",
+        include_str!("../templates/part_Cargo.toml.tmpl"),
+    ),
+];
+
+// Lays out a new day the same way as day 16: a lib crate holding the Solution
+// and a thin binary per part, then wires the lib into the workspace and runner.
+// A template file of the same name in `user_templates` replaces the built-in.
+//
+// Days of later years go under a directory named for the year, and their crate
+// names start with `y<year>_` so they cannot clash with the same day of
+// another year.
+pub fn new_day(root: &Path, year: u16, day: u8, user_templates: Option<&Path>) -> Result<()> {
+    let (day_path, crate_prefix) = if year == DEFAULT_YEAR {
+        (format!("day_{day:02}"), String::new())
+    } else {
+        (format!("{year}/day_{day:02}"), format!("y{year}_"))
+    };
+    let day_dir = root.join(&day_path);
+    if day_dir.exists() {
+        bail!("{} already exists", day_dir.display());
+    }
+    let templates = load_templates(user_templates)?;
+    let render = |name: &str, part: u8| {
+        let data = json!({
+            "year": year,
+            "day": day,
+            "day_padded": format!("{day:02}"),
+            "part": part,
+            "crate_prefix": crate_prefix,
+        });
+        templates
+            .render(name, &data)
+            .with_context(|| format!("Failed to render the {name} template"))
+    };
+
+    // Render everything before writing, so a broken template leaves no half
+    // made day behind
+    let mut files = vec![
+        (day_dir.join("lib/Cargo.toml"), render("lib_manifest", 0)?),
+        (day_dir.join("lib/src/lib.rs"), render("lib", 0)?),
+    ];
+    for part in 1..=2 {
+        let part_dir = day_dir.join(format!("part_{part}"));
+        let examples = day_dir.join("examples");
+        files.extend([
+            (part_dir.join("Cargo.toml"), render("part_manifest", part)?),
+            (part_dir.join("src/main.rs"), render("main", part)?),
+            (examples.join(format!("part_{part}.txt")), String::new()),
+            (examples.join(format!("part_{part}.answer")), String::new()),
+        ]);
+    }
+    for (path, contents) in files {
+        write_file(&path, &contents)?;
+    }
+
+    // Each year's crates are kept together, after the default year's
+    let lib = format!("{crate_prefix}day_{day:02}_lib");
+    let crate_prefixes = [format!("{crate_prefix}day_"), "day_".to_owned()];
+    let use_prefixes = crate_prefixes.clone().map(|prefix| format!("use {prefix}"));
+    update_file(&root.join("Cargo.toml"), |manifest| {
+        let manifest = add_year_members(manifest, year)?;
+        let dependency = format!("{lib} = {{ path = \"{day_path}/lib\" }}");
+        insert_after_last(&manifest, &crate_prefixes, &dependency)
+    })?;
+    update_file(&root.join("runner/Cargo.toml"), |manifest| {
+        insert_after_last(
+            manifest,
+            &crate_prefixes,
+            &format!("{lib}.workspace = true"),
+        )
+    })?;
+    update_file(&root.join("runner/src/days.rs"), |days| {
+        insert_after_last(days, &use_prefixes, &format!("use {lib} as _;"))
+    })?;
+    update_file(&root.join("runner/benches/days.rs"), |benches| {
+        let bench = format!("    bench_phases::<{lib}::Day{day}>(c, {year}, {day});");
+        insert_after_last(benches, &["    bench_phases::<"], &bench)
+    })?;
+
+    Ok(())
+}
+
+fn load_templates(user_templates: Option<&Path>) -> Result<Handlebars<'static>> {
+    let mut templates = Handlebars::new();
+    // These are Rust and TOML, not HTML
+    templates.register_escape_fn(handlebars::no_escape);
+    // So a misspelt variable in a user template is an error rather than blank
+    templates.set_strict_mode(true);
+
+    for (name, file, builtin) in TEMPLATES {
+        let user = user_templates
+            .map(|dir| dir.join(file))
+            .filter(|path| path.exists());
+        match user {
+            Some(path) => templates
+                .register_template_file(name, &path)
+                .with_context(|| format!("Failed to load {}", path.display()))?,
+            None => templates.register_template_string(name, builtin)?,
+        }
+    }
+    Ok(templates)
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+fn update_file(path: &Path, update: impl FnOnce(&str) -> Result<String>) -> Result<()> {
+    let contents =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let updated =
+        update(&contents).with_context(|| format!("Failed to update {}", path.display()))?;
+    fs::write(path, updated).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+// Keeps the new line next to the existing days rather than at the end of the
+// file, where it could land in the wrong table. The prefixes are tried in
+// order, so the first that matches any line decides where it goes.
+fn insert_after_last(contents: &str, prefixes: &[impl AsRef<str>], line: &str) -> Result<String> {
+    let mut lines: Vec<&str> = contents.lines().collect();
+    let index = prefixes
+        .iter()
+        .find_map(|prefix| lines.iter().rposition(|l| l.starts_with(prefix.as_ref())))
+        .context("No existing day to insert after")?;
+    lines.insert(index + 1, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+// Cargo refuses member globs that match nothing, so a year's globs are only
+// added along with its first day
+fn add_year_members(manifest: &str, year: u16) -> Result<String> {
+    if year == DEFAULT_YEAR {
+        return Ok(manifest.to_owned());
+    }
+    let members = format!("\"{year}/day_*/part_*\", \"{year}/day_*/lib\"");
+    let mut lines: Vec<String> = manifest.lines().map(str::to_owned).collect();
+    let line = lines
+        .iter_mut()
+        .find(|l| l.starts_with("members = ["))
+        .context("No workspace members to add the year to")?;
+    if !line.contains(&members) {
+        let end = line
+            .rfind(']')
+            .context("The workspace members are not one line")?;
+        line.insert_str(end, &format!(", {members}"));
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fake_workspace() -> tempfile::TempDir {
+        let root = tempfile::tempdir().unwrap();
+        fs::write(
+            root.path().join("Cargo.toml"),
+            "[workspace.dependencies]\naoc_core = { path = \"aoc_core\" }\nday_16_lib = { path = \"day_16/lib\" }\n\nanyhow = \"1.0.76\"\n",
+        )
+        .unwrap();
+        fs::create_dir_all(root.path().join("runner/src")).unwrap();
+        fs::write(
+            root.path().join("runner/Cargo.toml"),
+            "[dependencies]\nanyhow.workspace = true\n\nday_16_lib.workspace = true\n",
+        )
+        .unwrap();
+        fs::write(
+            root.path().join("runner/src/days.rs"),
+            "// Linked days\nuse day_16_lib as _;\n",
+        )
+        .unwrap();
+        fs::create_dir_all(root.path().join("runner/benches")).unwrap();
+        fs::write(
+            root.path().join("runner/benches/days.rs"),
+            "fn bench_solutions(c: &mut Criterion) {\n    bench_phases::<day_16_lib::Day16>(c, 2023, 16);\n}\n",
+        )
+        .unwrap();
+        root
+    }
+
+    #[test]
+    fn test_new_day_creates_crates() {
+        let root = fake_workspace();
+        new_day(root.path(), DEFAULT_YEAR, 18, None).unwrap();
+
+        let lib = fs::read_to_string(root.path().join("day_18/lib/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day18;"));
+        assert!(lib.contains("aoc_core::register_solution!(18, Day18);"));
+        assert!(lib.contains("indoc! {"));
+
+        let main = fs::read_to_string(root.path().join("day_18/part_2/src/main.rs")).unwrap();
+        assert!(main.contains("use day_18_lib::Day18;"));
+        assert!(main.contains("Day18::part2(&parsed)"));
+
+        let manifest = fs::read_to_string(root.path().join("day_18/part_1/Cargo.toml")).unwrap();
+        assert!(manifest.contains("name = \"day_18_part_1\""));
+
+        // Inputs are downloaded into the cache by the runner
+        assert!(!root.path().join("day_18/part_1/puzzle_input.txt").exists());
+
+        assert!(root.path().join("day_18/examples/part_2.txt").exists());
+        assert!(root.path().join("day_18/examples/part_2.answer").exists());
+    }
+
+    #[test]
+    fn test_new_day_wires_into_workspace_and_runner() {
+        let root = fake_workspace();
+        new_day(root.path(), DEFAULT_YEAR, 5, None).unwrap();
+
+        let workspace = fs::read_to_string(root.path().join("Cargo.toml")).unwrap();
+        assert_eq!(
+            workspace,
+            "[workspace.dependencies]\naoc_core = { path = \"aoc_core\" }\nday_16_lib = { path = \"day_16/lib\" }\nday_05_lib = { path = \"day_05/lib\" }\n\nanyhow = \"1.0.76\"\n"
+        );
+
+        let runner = fs::read_to_string(root.path().join("runner/Cargo.toml")).unwrap();
+        assert!(runner.ends_with("day_16_lib.workspace = true\nday_05_lib.workspace = true\n"));
+
+        let days = fs::read_to_string(root.path().join("runner/src/days.rs")).unwrap();
+        assert!(days.ends_with("use day_16_lib as _;\nuse day_05_lib as _;\n"));
+
+        let benches = fs::read_to_string(root.path().join("runner/benches/days.rs")).unwrap();
+        assert!(benches.contains(
+            "    bench_phases::<day_16_lib::Day16>(c, 2023, 16);\n    bench_phases::<day_05_lib::Day5>(c, 2023, 5);\n}"
+        ));
+    }
+
+    #[test]
+    fn test_new_day_in_a_later_year() {
+        let root = fake_workspace();
+        let manifest = root.path().join("Cargo.toml");
+        let workspace = fs::read_to_string(&manifest).unwrap();
+        fs::write(
+            &manifest,
+            format!("[workspace]\nmembers = [\"day_*/lib\", \"runner\"]\n\n{workspace}"),
+        )
+        .unwrap();
+        new_day(root.path(), 2024, 1, None).unwrap();
+        new_day(root.path(), 2024, 2, None).unwrap();
+
+        let lib = fs::read_to_string(root.path().join("2024/day_01/lib/src/lib.rs")).unwrap();
+        assert!(lib.contains("aoc_core::register_solution!(year = 2024, 1, Day1);"));
+        let main = fs::read_to_string(root.path().join("2024/day_02/part_1/src/main.rs")).unwrap();
+        assert!(main.contains("use y2024_day_02_lib::Day2;"));
+
+        let workspace = fs::read_to_string(&manifest).unwrap();
+        assert!(workspace.starts_with(
+            "[workspace]\nmembers = [\"day_*/lib\", \"runner\", \"2024/day_*/part_*\", \"2024/day_*/lib\"]\n"
+        ));
+        assert!(workspace.contains(
+            "day_16_lib = { path = \"day_16/lib\" }\ny2024_day_01_lib = { path = \"2024/day_01/lib\" }\ny2024_day_02_lib = { path = \"2024/day_02/lib\" }\n"
+        ));
+
+        let days = fs::read_to_string(root.path().join("runner/src/days.rs")).unwrap();
+        assert!(days.ends_with("use y2024_day_01_lib as _;\nuse y2024_day_02_lib as _;\n"));
+
+        // The default year's days keep to the top of the workspace
+        assert!(new_day(root.path(), DEFAULT_YEAR, 1, None).is_ok());
+        assert!(root.path().join("day_01").exists());
+    }
+
+    #[test]
+    fn test_new_day_refuses_existing_day() {
+        let root = fake_workspace();
+        fs::create_dir(root.path().join("day_16")).unwrap();
+
+        assert!(new_day(root.path(), DEFAULT_YEAR, 16, None).is_err());
+    }
+
+    #[test]
+    fn test_builtin_lib_includes_tests() {
+        let root = fake_workspace();
+        new_day(root.path(), DEFAULT_YEAR, 18, None).unwrap();
+
+        let lib = fs::read_to_string(root.path().join("day_18/lib/src/lib.rs")).unwrap();
+        assert!(
+            lib.contains("aoc_core::register_solution!(18, Day18);\n\n#[cfg(test)]\nmod tests {")
+        );
+        assert!(lib.ends_with("assert_eq!(Day18::part2(&parsed), 0);\n    }\n}\n"));
+    }
+
+    #[test]
+    fn test_user_templates_override_builtins() {
+        let root = fake_workspace();
+        let templates = tempfile::tempdir().unwrap();
+        fs::write(
+            templates.path().join("tests.rs.tmpl"),
+            "#[cfg(test)]\nmod day_{{day_padded}}_tests {}\n",
+        )
+        .unwrap();
+        fs::write(
+            templates.path().join("main.rs.tmpl"),
+            "fn main() { day_{{day_padded}}_lib::run({{part}}); }\n",
+        )
+        .unwrap();
+        new_day(root.path(), DEFAULT_YEAR, 7, Some(templates.path())).unwrap();
+
+        let lib = fs::read_to_string(root.path().join("day_07/lib/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day7;"));
+        assert!(lib.ends_with("#[cfg(test)]\nmod day_07_tests {}\n"));
+
+        let main = fs::read_to_string(root.path().join("day_07/part_2/src/main.rs")).unwrap();
+        assert_eq!(main, "fn main() { day_07_lib::run(2); }\n");
+    }
+
+    #[test]
+    fn test_user_template_with_unknown_variable_is_an_error() {
+        let root = fake_workspace();
+        let templates = tempfile::tempdir().unwrap();
+        fs::write(templates.path().join("main.rs.tmpl"), "{{dya}}").unwrap();
+
+        assert!(new_day(root.path(), DEFAULT_YEAR, 7, Some(templates.path())).is_err());
+        assert!(!root.path().join("day_07").exists());
+    }
+}
@@ -0,0 +1,34 @@
+use std::{env, fs};
+
+use anyhow::{Context, Result};
+
+use crate::config;
+
+const BASE_URL: &str = "https://adventofcode.com";
+// Advent of Code asks automated tools to identify themselves
+const USER_AGENT: &str = "github.com/Bluspur/AoC-2023 runner";
+
+// The session cookie from the AOC_SESSION environment variable, or else from
+// a gitignored `.aoc_session` file in the workspace root, or the session_file
+// in aoc.toml
+pub fn session() -> Result<String> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        return Ok(session.trim().to_owned());
+    }
+    let path = config::get().session_file();
+    let session = fs::read_to_string(&path).with_context(|| {
+        format!(
+            "No session cookie, set AOC_SESSION or put it in {}",
+            path.display()
+        )
+    })?;
+    Ok(session.trim().to_owned())
+}
+
+pub fn day_url(year: u16, day: u8) -> String {
+    format!("{BASE_URL}/{year}/day/{day}")
+}
+
+pub fn agent() -> ureq::Agent {
+    ureq::AgentBuilder::new().user_agent(USER_AGENT).build()
+}
@@ -0,0 +1,94 @@
+use std::{fmt, time::Duration};
+
+use crate::run_all::format_duration;
+
+// A quick summary of repeated runs of one part, for comparing changes without
+// reaching for the criterion benchmarks
+#[derive(Debug, PartialEq)]
+pub struct TimingStats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub stddev: Duration,
+}
+
+impl TimingStats {
+    pub fn from_samples(samples: &[Duration]) -> Option<TimingStats> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let middle = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[middle - 1] + sorted[middle]) / 2
+        } else {
+            sorted[middle]
+        };
+
+        // Sample standard deviation, a single run has none
+        let seconds: Vec<f64> = sorted.iter().map(Duration::as_secs_f64).collect();
+        let mean = seconds.iter().sum::<f64>() / seconds.len() as f64;
+        let variance = if seconds.len() > 1 {
+            seconds.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (seconds.len() - 1) as f64
+        } else {
+            0.0
+        };
+
+        Some(TimingStats {
+            runs: sorted.len(),
+            min: sorted[0],
+            median,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+impl fmt::Display for TimingStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "min {}, median {}, stddev {} over {} runs",
+            format_duration(self.min),
+            format_duration(self.median),
+            format_duration(self.stddev),
+            self.runs
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().copied().map(Duration::from_millis).collect()
+    }
+
+    #[test]
+    fn test_stats_odd_number_of_runs() {
+        let stats = TimingStats::from_samples(&millis(&[5, 1, 3])).unwrap();
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.stddev, Duration::from_millis(2));
+    }
+
+    #[test]
+    fn test_stats_even_number_of_runs() {
+        let stats = TimingStats::from_samples(&millis(&[4, 1, 2, 3])).unwrap();
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(
+            stats.to_string(),
+            "min 1.000 ms, median 2.500 ms, stddev 1.291 ms over 4 runs"
+        );
+    }
+
+    #[test]
+    fn test_stats_single_and_no_runs() {
+        let stats = TimingStats::from_samples(&millis(&[7])).unwrap();
+        assert_eq!(stats.stddev, Duration::ZERO);
+
+        assert_eq!(TimingStats::from_samples(&[]), None);
+    }
+}
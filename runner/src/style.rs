@@ -0,0 +1,84 @@
+use std::{env, io::IsTerminal};
+
+use colored::Colorize;
+
+// How summaries are drawn. Colour is only used on a terminal, and without it
+// the output is plain ASCII so it reads the same in logs and pipes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Style {
+    color: bool,
+}
+
+impl Style {
+    // Colours stdout unless asked not to, by --no-color or NO_COLOR
+    pub fn for_stdout(no_color: bool) -> Style {
+        let color =
+            !no_color && env::var_os("NO_COLOR").is_none() && std::io::stdout().is_terminal();
+        // colored checks this itself, so it has to agree with us
+        colored::control::set_override(color);
+        Style::new(color)
+    }
+
+    pub fn new(color: bool) -> Style {
+        Style { color }
+    }
+
+    pub fn pass(&self, text: &str) -> String {
+        self.paint(text, |text| text.green().to_string())
+    }
+
+    pub fn fail(&self, text: &str) -> String {
+        self.paint(text, |text| text.red().bold().to_string())
+    }
+
+    pub fn skip(&self, text: &str) -> String {
+        self.paint(text, |text| text.dimmed().to_string())
+    }
+
+    pub fn slowest(&self, text: &str) -> String {
+        self.paint(text, |text| text.yellow().bold().to_string())
+    }
+
+    // One star per solved part, like the calendar on the site
+    pub fn stars(&self, count: usize) -> String {
+        let noun = if count == 1 { "star" } else { "stars" };
+        if count == 0 {
+            format!("0 {noun}")
+        } else if self.color {
+            format!("{} {count} {noun}", "★".repeat(count).yellow().bold())
+        } else {
+            format!("{} {count} {noun}", "*".repeat(count))
+        }
+    }
+
+    fn paint(&self, text: &str, color: impl FnOnce(&str) -> String) -> String {
+        if self.color {
+            color(text)
+        } else {
+            text.to_owned()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plain_style_is_unchanged_ascii() {
+        let style = Style::new(false);
+        assert_eq!(style.fail("FAIL"), "FAIL");
+        assert_eq!(style.slowest("  1.000 ms"), "  1.000 ms");
+        assert_eq!(style.stars(3), "*** 3 stars");
+        assert_eq!(style.stars(1), "* 1 star");
+        assert_eq!(style.stars(0), "0 stars");
+    }
+
+    #[test]
+    fn test_color_style() {
+        colored::control::set_override(true);
+        let style = Style::new(true);
+        assert_eq!(style.pass("pass"), "\u{1b}[32mpass\u{1b}[0m");
+        assert!(style.stars(2).contains("★★"));
+    }
+}
@@ -0,0 +1,311 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{bail, Context, Result};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::site;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Response {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited,
+    AlreadySolved,
+    Unknown,
+}
+
+// One line of the local submission history
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Submission {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub response: Response,
+    pub message: String,
+    pub submitted_at: u64,
+    // Unix time before which the site will refuse another answer
+    pub wait_until: Option<u64>,
+}
+
+// Posts an answer unless the history already shows what the site would say,
+// then records the response. Each year keeps a history of its own.
+pub fn submit(
+    history_path: &Path,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Submission> {
+    let history = read_history(history_path)?;
+    let now = unix_now();
+    check_history(&history, day, part, answer, now)?;
+
+    let body = site::agent()
+        .post(&format!("{}/answer", site::day_url(year, day)))
+        .set("Cookie", &format!("session={}", site::session()?))
+        .send_form(&[("level", &part.to_string()), ("answer", answer)])
+        .context("Failed to submit the answer")?
+        .into_string()?;
+
+    let (response, message, wait) = parse_response(&body);
+    let submission = Submission {
+        day,
+        part,
+        answer: answer.to_owned(),
+        response,
+        message,
+        submitted_at: now,
+        wait_until: wait.map(|seconds| now + seconds),
+    };
+    append_history(history_path, &submission)?;
+    Ok(submission)
+}
+
+pub fn read_history(path: &Path) -> Result<Vec<Submission>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str(line).context("Invalid submission history"))
+        .collect()
+}
+
+fn append_history(path: &Path, submission: &Submission) -> Result<()> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("Failed to open {}", path.display()))?;
+    writeln!(file, "{}", serde_json::to_string(submission)?)?;
+    Ok(())
+}
+
+// Refuses submissions the site would reject or that are already known to be
+// wrong, since each wrong answer adds to the lockout
+fn check_history(history: &[Submission], day: u8, part: u8, answer: &str, now: u64) -> Result<()> {
+    let previous: Vec<_> = history
+        .iter()
+        .filter(|s| s.day == day && s.part == part)
+        .collect();
+
+    if let Some(correct) = previous.iter().find(|s| s.response == Response::Correct) {
+        bail!(
+            "Day {day} part {part} was already solved with {}",
+            correct.answer
+        );
+    }
+    // Answers the site never judged, e.g. when rate limited, can be retried
+    if let Some(same) = previous.iter().find(|s| {
+        s.answer == answer
+            && matches!(
+                s.response,
+                Response::Wrong | Response::TooHigh | Response::TooLow
+            )
+    }) {
+        bail!("{answer} was already submitted: {}", same.message);
+    }
+    if let Some(wait_until) = previous.iter().filter_map(|s| s.wait_until).max() {
+        if wait_until > now {
+            bail!("Submitting too soon, wait another {}s", wait_until - now);
+        }
+    }
+
+    if let Ok(value) = answer.parse::<i128>() {
+        for submission in &previous {
+            let Ok(bound) = submission.answer.parse::<i128>() else {
+                continue;
+            };
+            match submission.response {
+                Response::TooHigh if value >= bound => {
+                    bail!("{answer} is not below {bound}, which was too high")
+                }
+                Response::TooLow if value <= bound => {
+                    bail!("{answer} is not above {bound}, which was too low")
+                }
+                _ => {}
+            }
+        }
+    }
+    Ok(())
+}
+
+// Classifies the page returned after posting an answer, along with how many
+// seconds the site says to wait before trying again
+fn parse_response(body: &str) -> (Response, String, Option<u64>) {
+    let message = article_text(body);
+    let response = if message.contains("That's the right answer") {
+        Response::Correct
+    } else if message.contains("too high") {
+        Response::TooHigh
+    } else if message.contains("too low") {
+        Response::TooLow
+    } else if message.contains("That's not the right answer") {
+        Response::Wrong
+    } else if message.contains("You gave an answer too recently") {
+        Response::RateLimited
+    } else if message.contains("Did you already complete it") {
+        Response::AlreadySolved
+    } else {
+        Response::Unknown
+    };
+    let wait = wait_seconds(&message);
+    (response, message, wait)
+}
+
+fn article_text(body: &str) -> String {
+    let article = body
+        .split_once("<article>")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(body, |(article, _)| article);
+    let tags = Regex::new(r"<[^>]*>").unwrap();
+    tags.replace_all(article, "").trim().to_owned()
+}
+
+// Either "You have 4m 59s left to wait" after answering too soon, or "please
+// wait one minute" / "wait 5 minutes" after a wrong answer
+fn wait_seconds(message: &str) -> Option<u64> {
+    let left = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+    if let Some(captures) = left.captures(message) {
+        let minutes: u64 = captures.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+        let seconds: u64 = captures[2].parse().unwrap();
+        return Some(minutes * 60 + seconds);
+    }
+    if message.contains("wait one minute") {
+        return Some(60);
+    }
+    let minutes = Regex::new(r"wait (\d+) minutes").unwrap();
+    minutes
+        .captures(message)
+        .map(|captures| captures[1].parse::<u64>().unwrap() * 60)
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("The clock is after 1970")
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn submission(answer: &str, response: Response, wait_until: Option<u64>) -> Submission {
+        Submission {
+            day: 16,
+            part: 1,
+            answer: answer.to_owned(),
+            response,
+            message: format!("{response:?}"),
+            submitted_at: 0,
+            wait_until,
+        }
+    }
+
+    #[test]
+    fn test_parse_right_answer() {
+        let body = "<html><main><article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article></main></html>";
+        let (response, message, wait) = parse_response(body);
+        assert_eq!(response, Response::Correct);
+        assert_eq!(
+            message,
+            "That's the right answer! You are one gold star closer."
+        );
+        assert_eq!(wait, None);
+    }
+
+    #[test]
+    fn test_parse_too_high() {
+        let body = "<article><p>That's not the right answer; your answer is too high. Please wait one minute before trying again.</p></article>";
+        let (response, _, wait) = parse_response(body);
+        assert_eq!(response, Response::TooHigh);
+        assert_eq!(wait, Some(60));
+    }
+
+    #[test]
+    fn test_parse_rate_limited() {
+        let body = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 59s left to wait.</p></article>";
+        let (response, _, wait) = parse_response(body);
+        assert_eq!(response, Response::RateLimited);
+        assert_eq!(wait, Some(299));
+
+        let body =
+            "<article><p>You gave an answer too recently. You have 35s left to wait.</p></article>";
+        assert_eq!(parse_response(body).2, Some(35));
+    }
+
+    #[test]
+    fn test_parse_wrong_with_longer_wait() {
+        let body = "<article><p>That's not the right answer. Please wait 5 minutes before trying again.</p></article>";
+        let (response, _, wait) = parse_response(body);
+        assert_eq!(response, Response::Wrong);
+        assert_eq!(wait, Some(300));
+    }
+
+    #[test]
+    fn test_history_blocks_known_answers() {
+        let history = [submission("46", Response::Wrong, None)];
+        assert!(check_history(&history, 16, 1, "46", 100).is_err());
+        assert!(check_history(&history, 16, 1, "47", 100).is_ok());
+        // Other parts are unaffected
+        assert!(check_history(&history, 16, 2, "46", 100).is_ok());
+
+        let history = [submission("46", Response::Correct, None)];
+        assert!(check_history(&history, 16, 1, "47", 100).is_err());
+    }
+
+    #[test]
+    fn test_history_blocks_while_rate_limited() {
+        let history = [submission("46", Response::Wrong, Some(160))];
+        assert!(check_history(&history, 16, 1, "47", 100).is_err());
+        assert!(check_history(&history, 16, 1, "47", 160).is_ok());
+    }
+
+    #[test]
+    fn test_history_allows_retrying_unjudged_answers() {
+        let history = [submission("46", Response::RateLimited, Some(160))];
+        assert!(check_history(&history, 16, 1, "46", 100).is_err());
+        assert!(check_history(&history, 16, 1, "46", 160).is_ok());
+
+        let history = [submission("46", Response::Unknown, None)];
+        assert!(check_history(&history, 16, 1, "46", 0).is_ok());
+    }
+
+    #[test]
+    fn test_history_blocks_answers_outside_bounds() {
+        let history = [
+            submission("100", Response::TooHigh, None),
+            submission("20", Response::TooLow, None),
+        ];
+        assert!(check_history(&history, 16, 1, "120", 0).is_err());
+        assert!(check_history(&history, 16, 1, "10", 0).is_err());
+        assert!(check_history(&history, 16, 1, "50", 0).is_ok());
+    }
+
+    #[test]
+    fn test_history_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("submissions.jsonl");
+        assert!(read_history(&path).unwrap().is_empty());
+
+        let first = submission("46", Response::TooLow, Some(60));
+        append_history(&path, &first).unwrap();
+        append_history(&path, &submission("51", Response::Correct, None)).unwrap();
+
+        let history = read_history(&path).unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0], first);
+    }
+}
@@ -0,0 +1,184 @@
+use std::{collections::BTreeMap, fmt::Write, fs, path::Path};
+
+use anyhow::{bail, Context, Result};
+
+use crate::{run_all::PartResult, style::Style};
+
+// Known good answers, one `day part answer` per line. Blank lines and lines
+// starting with `#` are ignored.
+pub fn read_answers(path: &Path) -> Result<BTreeMap<(u8, u8), String>> {
+    let contents =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    parse_answers(&contents).with_context(|| format!("Failed to parse {}", path.display()))
+}
+
+fn parse_answers(contents: &str) -> Result<BTreeMap<(u8, u8), String>> {
+    let mut answers = BTreeMap::new();
+    for (number, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<_> = line.split_whitespace().collect();
+        let [day, part, answer] = fields[..] else {
+            bail!("Line {} should be `day part answer`", number + 1);
+        };
+        let day = day
+            .parse()
+            .with_context(|| format!("Line {} has an invalid day", number + 1))?;
+        let part = part
+            .parse()
+            .with_context(|| format!("Line {} has an invalid part", number + 1))?;
+        answers.insert((day, part), answer.to_owned());
+    }
+    Ok(answers)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String, actual: String },
+    Error(String),
+    NoAnswer,
+}
+
+pub fn verify(results: &[PartResult], answers: &BTreeMap<(u8, u8), String>) -> Vec<Verdict> {
+    results
+        .iter()
+        .map(|result| {
+            let Some(expected) = answers.get(&(result.day, result.part)) else {
+                return Verdict::NoAnswer;
+            };
+            match &result.outcome {
+                Ok(timed) if &timed.answer == expected => Verdict::Pass,
+                Ok(timed) => Verdict::Fail {
+                    expected: expected.clone(),
+                    actual: timed.answer.clone(),
+                },
+                Err(error) => Verdict::Error(format!("{error:#}")),
+            }
+        })
+        .collect()
+}
+
+// One line per part that failed, for stderr once the report is printed.
+// Parts without a stored answer are not failures.
+pub fn format_failures(results: &[PartResult], verdicts: &[Verdict]) -> String {
+    let mut failures = String::new();
+    for (result, verdict) in results.iter().zip(verdicts) {
+        let reason = match verdict {
+            Verdict::Fail { expected, actual } => format!("expected {expected} but got {actual}"),
+            Verdict::Error(error) => error.clone(),
+            Verdict::Pass | Verdict::NoAnswer => continue,
+        };
+        writeln!(
+            failures,
+            "Day {:02} Part {} failed: {reason}",
+            result.day, result.part
+        )
+        .unwrap();
+    }
+    failures
+}
+
+pub fn format_verdicts(results: &[PartResult], verdicts: &[Verdict], style: Style) -> String {
+    let mut report = String::new();
+    for (result, verdict) in results.iter().zip(verdicts) {
+        let status = match verdict {
+            Verdict::Pass => style.pass("pass"),
+            Verdict::Fail { expected, actual } => {
+                style.fail(&format!("FAIL, expected {expected} but got {actual}"))
+            }
+            Verdict::Error(error) => style.fail(&format!("FAIL, {error}")),
+            Verdict::NoAnswer => style.skip("skipped, no stored answer"),
+        };
+        writeln!(
+            report,
+            "Day {:02} Part {}: {status}",
+            result.day, result.part
+        )
+        .unwrap();
+    }
+
+    let count = |f: fn(&Verdict) -> bool| verdicts.iter().filter(|v| f(v)).count();
+    let passed = count(|v| *v == Verdict::Pass);
+    writeln!(
+        report,
+        "{passed} passed, {} failed, {} skipped",
+        count(|v| matches!(v, Verdict::Fail { .. } | Verdict::Error(_))),
+        count(|v| *v == Verdict::NoAnswer)
+    )
+    .unwrap();
+    writeln!(report, "{}", style.stars(passed)).unwrap();
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+    use aoc_core::TimedAnswer;
+
+    use super::*;
+
+    fn answered(day: u8, part: u8, answer: &str) -> PartResult {
+        PartResult {
+            day,
+            part,
+            outcome: Ok(TimedAnswer {
+                answer: answer.to_owned(),
+                parse: None,
+                solve: Default::default(),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = parse_answers("# day part answer\n14 1 136\n\n14 2  64\n").unwrap();
+        assert_eq!(
+            answers,
+            BTreeMap::from([((14, 1), "136".to_owned()), ((14, 2), "64".to_owned())])
+        );
+    }
+
+    #[test]
+    fn test_parse_answers_rejects_malformed_lines() {
+        assert!(parse_answers("14 1").is_err());
+        assert!(parse_answers("fourteen 1 136").is_err());
+    }
+
+    #[test]
+    fn test_verify_and_format() {
+        let results = [
+            answered(14, 1, "136"),
+            answered(14, 2, "65"),
+            PartResult {
+                day: 16,
+                part: 1,
+                outcome: Err(anyhow!("missing input")),
+            },
+            answered(16, 2, "51"),
+        ];
+        let answers = parse_answers("14 1 136\n14 2 64\n16 1 46").unwrap();
+
+        let verdicts = verify(&results, &answers);
+        assert_eq!(
+            format_verdicts(&results, &verdicts, Style::new(false)),
+            "\
+Day 14 Part 1: pass
+Day 14 Part 2: FAIL, expected 64 but got 65
+Day 16 Part 1: FAIL, missing input
+Day 16 Part 2: skipped, no stored answer
+1 passed, 2 failed, 1 skipped
+* 1 star
+"
+        );
+        assert_eq!(
+            format_failures(&results, &verdicts),
+            "\
+Day 14 Part 2 failed: expected 64 but got 65
+Day 16 Part 1 failed: missing input
+"
+        );
+    }
+}
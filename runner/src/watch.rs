@@ -0,0 +1,136 @@
+use std::{
+    path::Path,
+    process::{Command, Stdio},
+    sync::mpsc,
+    time::Duration,
+};
+
+use anyhow::{bail, Context, Result};
+use notify::{RecursiveMode, Watcher};
+
+use crate::{json::JsonResult, paths::day_dir, run_all::format_duration};
+
+// How long to wait for an editor to finish writing before re-running, so one
+// save that touches several files only triggers one run
+const SETTLE: Duration = Duration::from_millis(200);
+
+// Re-runs one part whenever anything under its day changes. The solvers are
+// compiled into the runner, so each run goes through `cargo run` to pick up
+// source edits as well as input changes.
+pub fn watch(root: &Path, year: u16, day: u8, part: u8, threads: Option<usize>) -> Result<()> {
+    let day_dir = day_dir(year, day);
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender)?;
+    watcher
+        .watch(&day_dir, RecursiveMode::Recursive)
+        .with_context(|| format!("Failed to watch {}", day_dir.display()))?;
+    println!("Watching {} for changes", day_dir.display());
+
+    let mut previous = None;
+    loop {
+        match run_once(root, year, day, part, threads) {
+            Ok(result) => {
+                println!("{}", format_change(previous.as_ref(), &result));
+                previous = Some(result);
+            }
+            Err(error) => eprintln!("{error:#}"),
+        }
+
+        // Block until something changes, then let the burst of events settle
+        receiver.recv().context("Stopped receiving file events")??;
+        while receiver.recv_timeout(SETTLE).is_ok() {}
+    }
+}
+
+fn run_once(
+    root: &Path,
+    year: u16,
+    day: u8,
+    part: u8,
+    threads: Option<usize>,
+) -> Result<JsonResult> {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_owned());
+    let mut command = Command::new(cargo);
+    command
+        .current_dir(root)
+        .args(["run", "--quiet", "--release", "--package", "aoc2023", "--"])
+        .args([
+            "--year",
+            &year.to_string(),
+            "--day",
+            &day.to_string(),
+            "--part",
+            &part.to_string(),
+            "--json",
+        ]);
+    if let Some(threads) = threads {
+        command.args(["--threads", &threads.to_string()]);
+    }
+    let output = command
+        .stderr(Stdio::inherit())
+        .output()
+        .context("Failed to start cargo")?;
+    if !output.status.success() {
+        bail!("Day {day} part {part} failed, waiting for the next change");
+    }
+    serde_json::from_slice(&output.stdout).context("Unexpected output from the runner")
+}
+
+fn format_change(previous: Option<&JsonResult>, current: &JsonResult) -> String {
+    let answer = current.answer.as_deref().unwrap_or("none");
+    let answer_change = match previous.and_then(|p| p.answer.as_deref()) {
+        Some(before) if before == answer => " (unchanged)".to_owned(),
+        Some(before) => format!(" (was {before})"),
+        None => String::new(),
+    };
+    let time = current.parse_ms.unwrap_or(0.0) + current.solve_ms.unwrap_or(0.0);
+    let time_change = match previous {
+        Some(before) => {
+            let before = before.parse_ms.unwrap_or(0.0) + before.solve_ms.unwrap_or(0.0);
+            format!(" ({:+.3} ms)", time - before)
+        }
+        None => String::new(),
+    };
+    format!(
+        "Day {:02} Part {}: {answer}{answer_change} in {}{time_change}",
+        current.day,
+        current.part,
+        format_duration(Duration::from_secs_f64(time / 1000.0))
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(answer: &str, solve_ms: f64) -> JsonResult {
+        JsonResult {
+            day: 16,
+            part: 2,
+            answer: Some(answer.to_owned()),
+            parse_ms: Some(0.5),
+            solve_ms: Some(solve_ms),
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_format_first_run() {
+        assert_eq!(
+            format_change(None, &result("51", 1.5)),
+            "Day 16 Part 2: 51 in 2.000 ms"
+        );
+    }
+
+    #[test]
+    fn test_format_change() {
+        assert_eq!(
+            format_change(Some(&result("51", 1.5)), &result("51", 1.0)),
+            "Day 16 Part 2: 51 (unchanged) in 1.500 ms (-0.500 ms)"
+        );
+        assert_eq!(
+            format_change(Some(&result("50", 1.0)), &result("51", 2.0)),
+            "Day 16 Part 2: 51 (was 50) in 2.500 ms (+1.000 ms)"
+        );
+    }
+}